@@ -17,6 +17,7 @@ import (
 
 	"github.com/openimsdk/openim-sdk-core/v3/internal/third/file"
 	"github.com/openimsdk/openim-sdk-core/v3/open_im_sdk_callback"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/ccontext"
 	"github.com/openimsdk/openim-sdk-core/v3/pkg/common"
 	"github.com/openimsdk/openim-sdk-core/v3/pkg/constant"
 	"github.com/openimsdk/openim-sdk-core/v3/pkg/content_type"
@@ -101,6 +102,23 @@ func (c *Conversation) GetMultipleConversation(ctx context.Context, conversation
 
 }
 
+// GetConversationRecvMessageOpt returns the recvMsgOpt of the given conversations with a single local query,
+// conversations that do not exist locally are left out.
+func (c *Conversation) GetConversationRecvMessageOpt(ctx context.Context, conversationIDs []string) ([]*sdk_params_callback.ConversationRecvMsgOpt, error) {
+	conversations, err := c.db.GetMultipleConversationDB(ctx, conversationIDs)
+	if err != nil {
+		return nil, err
+	}
+	opts := make([]*sdk_params_callback.ConversationRecvMsgOpt, 0, len(conversations))
+	for _, conversation := range conversations {
+		opts = append(opts, &sdk_params_callback.ConversationRecvMsgOpt{
+			ConversationID: conversation.ConversationID,
+			RecvMsgOpt:     conversation.RecvMsgOpt,
+		})
+	}
+	return opts, nil
+}
+
 func (c *Conversation) HideAllConversations(ctx context.Context) error {
 	err := c.db.ResetAllConversation(ctx)
 	if err != nil {
@@ -277,6 +295,9 @@ func (c *Conversation) SendMessage(ctx context.Context, s *sdk_struct.MsgStruct,
 		}
 		return ""
 	}
+	if err := c.checkMsgContentSize(ctx, s, true); err != nil {
+		return nil, err
+	}
 	options := make(map[string]bool, 2)
 	lc, err := c.checkID(ctx, s, recvID, groupID, options)
 	if err != nil {
@@ -304,6 +325,9 @@ func (c *Conversation) SendMessage(ctx context.Context, s *sdk_struct.MsgStruct,
 				return nil, sdkerrs.ErrMsgRepeated
 			} else {
 				s.Status = constant.MsgStatusSending
+				if err := c.updateConvertedMsgContent(ctx, lc.ConversationID, oldMessage, s); err != nil {
+					return nil, err
+				}
 				err = c.db.InsertSendingMessage(ctx, &model_struct.LocalSendingMessages{
 					ConversationID: lc.ConversationID,
 					ClientMsgID:    s.ClientMsgID,
@@ -534,6 +558,9 @@ func (c *Conversation) SendMessage(ctx context.Context, s *sdk_struct.MsgStruct,
 
 func (c *Conversation) SendMessageNotOss(ctx context.Context, s *sdk_struct.MsgStruct, recvID, groupID string,
 	p *sdkws.OfflinePushInfo, isOnlineOnly bool) (*sdk_struct.MsgStruct, error) {
+	if err := c.checkMsgContentSize(ctx, s, false); err != nil {
+		return nil, err
+	}
 	options := make(map[string]bool, 2)
 	lc, err := c.checkID(ctx, s, recvID, groupID, options)
 	if err != nil {
@@ -614,8 +641,18 @@ func (c *Conversation) SendMessageNotOss(ctx context.Context, s *sdk_struct.MsgS
 	return c.sendMessageToServer(ctx, s, lc, callback, delFile, p, options, isOnlineOnly)
 }
 
+// applyDefaultSendOptions fills the options a message does not set itself from IMConfig.DefaultSendOptions.
+func applyDefaultSendOptions(ctx context.Context, options map[string]bool) {
+	for key, value := range ccontext.Info(ctx).DefaultSendOptions() {
+		if _, ok := options[key]; !ok {
+			options[key] = value
+		}
+	}
+}
+
 func (c *Conversation) sendMessageToServer(ctx context.Context, s *sdk_struct.MsgStruct, lc *model_struct.LocalConversation, callback open_im_sdk_callback.SendMsgCallBack,
 	delFiles []string, offlinePushInfo *sdkws.OfflinePushInfo, options map[string]bool, isOnlineOnly bool) (*sdk_struct.MsgStruct, error) {
+	applyDefaultSendOptions(ctx, options)
 	if isOnlineOnly {
 		utils.SetSwitchFromOptions(options, constant.IsHistory, false)
 		utils.SetSwitchFromOptions(options, constant.IsPersistent, false)
@@ -679,6 +716,7 @@ func (c *Conversation) sendMessageToServer(ctx context.Context, s *sdk_struct.Ms
 			}
 			// log.ZDebug(ctx, "remove temp file:", "file", file)
 		}
+		c.pendingThumbnails.Delete(s.ClientMsgID)
 
 		c.updateMsgStatusAndTriggerConversation(ctx, sendMsgResp.ClientMsgID, sendMsgResp.ServerMsgID, sendMsgResp.SendTime, constant.MsgStatusSendSuccess, s, lc, isOnlineOnly)
 	}()
@@ -686,6 +724,76 @@ func (c *Conversation) sendMessageToServer(ctx context.Context, s *sdk_struct.Ms
 
 }
 
+// msgContentSize returns the size in bytes of the content the user put in the message.
+// Media are uploaded separately, so only text and custom payloads count.
+func msgContentSize(s *sdk_struct.MsgStruct) int {
+	switch {
+	case s.ContentType == constant.Text && s.TextElem != nil:
+		return len(s.TextElem.Content)
+	case s.ContentType == constant.AdvancedText && s.AdvancedTextElem != nil:
+		return len(s.AdvancedTextElem.Text)
+	case s.ContentType == constant.AtText && s.AtTextElem != nil:
+		return len(s.AtTextElem.Text)
+	case s.ContentType == constant.Quote && s.QuoteElem != nil:
+		return len(s.QuoteElem.Text)
+	case s.ContentType == constant.Custom && s.CustomElem != nil:
+		return len(s.CustomElem.Data) + len(s.CustomElem.Description) + len(s.CustomElem.Extension)
+	case s.ContentType == constant.Merger && s.MergeElem != nil:
+		return len(utils.StructToJsonString(s.MergeElem))
+	}
+	return 0
+}
+
+// checkMsgContentSize rejects messages whose content exceeds MaxMsgContentSize before they are stored or sent.
+// With ConvertOversizedTextToFile set and canConvert true, an oversized text message is turned into a file message instead.
+func (c *Conversation) checkMsgContentSize(ctx context.Context, s *sdk_struct.MsgStruct, canConvert bool) error {
+	maxSize := ccontext.Info(ctx).MaxMsgContentSize()
+	if maxSize <= 0 {
+		return nil
+	}
+	size := msgContentSize(s)
+	if size <= maxSize {
+		return nil
+	}
+	if canConvert && s.ContentType == constant.Text && ccontext.Info(ctx).ConvertOversizedTextToFile() {
+		return c.textMsgToFile(s)
+	}
+	return sdkerrs.ErrMsgContentTooLarge.WrapMsg(fmt.Sprintf("content size %d exceeds %d", size, maxSize))
+}
+
+// updateConvertedMsgContent rewrites the stored content of a resent message whose content type changed,
+// e.g. a text message that failed to send and is now converted to a file message by checkMsgContentSize.
+func (c *Conversation) updateConvertedMsgContent(ctx context.Context, conversationID string, oldMessage *model_struct.LocalChatLog, s *sdk_struct.MsgStruct) error {
+	if oldMessage.ContentType == s.ContentType {
+		return nil
+	}
+	localMessage := MsgStructToLocalChatLog(s)
+	return c.db.UpdateMessage(ctx, conversationID, &model_struct.LocalChatLog{
+		ClientMsgID: localMessage.ClientMsgID,
+		ContentType: localMessage.ContentType,
+		Content:     localMessage.Content,
+	})
+}
+
+// textMsgToFile turns a text message into a .txt file message. The text is written to the temporary file
+// path of the message, the file branch of SendMessage uploads it and removes it once sent.
+func (c *Conversation) textMsgToFile(s *sdk_struct.MsgStruct) error {
+	fileName := fmt.Sprintf("msg_text_%s.txt", s.ClientMsgID)
+	content := []byte(s.TextElem.Content)
+	if err := os.WriteFile(utils.FileTmpPath(fileName, c.DataDir), content, 0644); err != nil {
+		return errs.WrapMsg(err, "write oversized text to file failed")
+	}
+	s.ContentType = constant.File
+	s.TextElem = nil
+	s.FileElem = &sdk_struct.FileElem{
+		FilePath: fileName,
+		FileName: fileName,
+		FileSize: int64(len(content)),
+		FileType: "text/plain",
+	}
+	return nil
+}
+
 func (c *Conversation) sendMsg(ctx context.Context, s *sdk_struct.MsgStruct, wsMsgData *sdkws.MsgData, sendMsgResp *msg.SendMsgResp) error {
 	if sendMsgResp == nil {
 		sendMsgResp = &msg.SendMsgResp{}
@@ -802,6 +910,24 @@ func (c *Conversation) MarkAllConversationMessageAsRead(ctx context.Context) err
 	return nil
 }
 
+func (c *Conversation) RecalculateUnreadCount(ctx context.Context, conversationID string) error {
+	return c.recalculateUnreadCount(ctx, conversationID)
+}
+
+func (c *Conversation) RecalculateAllUnreadCount(ctx context.Context) error {
+	c.conversationSyncMutex.Lock()
+	defer c.conversationSyncMutex.Unlock()
+	conversationChangedIDs, err := c.syncAllConversationHasReadSeqs(ctx)
+	if err != nil {
+		return err
+	}
+	if len(conversationChangedIDs) > 0 {
+		c.doUpdateConversation(common.Cmd2Value{Value: common.UpdateConNode{Action: constant.ConChange, Args: conversationChangedIDs}, Ctx: ctx})
+	}
+	c.doUpdateConversation(common.Cmd2Value{Value: common.UpdateConNode{Action: constant.TotalUnreadMessageChanged}, Ctx: ctx})
+	return nil
+}
+
 // deprecated
 func (c *Conversation) MarkMessagesAsReadByMsgID(ctx context.Context, conversationID string, clientMsgIDs []string) error {
 	return c.markMessagesAsReadByMsgID(ctx, conversationID, clientMsgIDs)
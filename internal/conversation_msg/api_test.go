@@ -0,0 +1,160 @@
+//go:build !js
+// +build !js
+
+package conversation_msg
+
+import (
+	"context"
+	"os"
+	"strings"
+	"testing"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/ccontext"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/constant"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/sdkerrs"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/utils"
+	"github.com/openimsdk/openim-sdk-core/v3/sdk_struct"
+)
+
+func withTestConfig(conf *sdk_struct.IMConfig) context.Context {
+	return ccontext.WithInfo(context.Background(), &ccontext.GlobalConfig{UserID: testLoginUserID, IMConfig: conf})
+}
+
+func TestCheckMsgContentSize(t *testing.T) {
+	c := &Conversation{DataDir: t.TempDir() + "/"}
+	text := strings.Repeat("a", 11)
+	newText := func() *sdk_struct.MsgStruct {
+		return &sdk_struct.MsgStruct{ClientMsgID: "text", ContentType: constant.Text, TextElem: &sdk_struct.TextElem{Content: text}}
+	}
+
+	ctx := withTestConfig(&sdk_struct.IMConfig{MaxMsgContentSize: 10})
+	if err := c.checkMsgContentSize(ctx, newText(), true); !sdkerrs.ErrMsgContentTooLarge.Is(err) {
+		t.Fatalf("oversized text: err = %v, want ErrMsgContentTooLarge", err)
+	}
+	custom := &sdk_struct.MsgStruct{ContentType: constant.Custom, CustomElem: &sdk_struct.CustomElem{Data: text}}
+	if err := c.checkMsgContentSize(ctx, custom, true); !sdkerrs.ErrMsgContentTooLarge.Is(err) {
+		t.Fatalf("oversized custom: err = %v, want ErrMsgContentTooLarge", err)
+	}
+	if err := c.checkMsgContentSize(withTestConfig(&sdk_struct.IMConfig{MaxMsgContentSize: 11}), newText(), true); err != nil {
+		t.Fatalf("text at the limit: err = %v", err)
+	}
+	if err := c.checkMsgContentSize(withTestConfig(&sdk_struct.IMConfig{}), newText(), true); err != nil {
+		t.Fatalf("no limit: err = %v", err)
+	}
+
+	ctx = withTestConfig(&sdk_struct.IMConfig{MaxMsgContentSize: 10, ConvertOversizedTextToFile: true})
+	if err := c.checkMsgContentSize(ctx, newText(), false); !sdkerrs.ErrMsgContentTooLarge.Is(err) {
+		t.Fatalf("oversized text without upload: err = %v, want ErrMsgContentTooLarge", err)
+	}
+	s := newText()
+	if err := c.checkMsgContentSize(ctx, s, true); err != nil {
+		t.Fatal(err)
+	}
+	if s.ContentType != constant.File || s.TextElem != nil || s.FileElem == nil {
+		t.Fatalf("oversized text was not converted to a file message: %+v", s)
+	}
+	content, err := os.ReadFile(utils.FileTmpPath(s.FileElem.FilePath, c.DataDir))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if string(content) != text || s.FileElem.FileSize != int64(len(text)) {
+		t.Errorf("file content = %q (size %d), want %q", content, s.FileElem.FileSize, text)
+	}
+}
+
+func TestApplyDefaultSendOptions(t *testing.T) {
+	ctx := withTestConfig(&sdk_struct.IMConfig{DefaultSendOptions: map[string]bool{
+		constant.IsOfflinePush: false,
+		constant.IsUnreadCount: false,
+	}})
+	options := map[string]bool{constant.IsOfflinePush: true, constant.IsHistory: false}
+	applyDefaultSendOptions(ctx, options)
+	want := map[string]bool{constant.IsOfflinePush: true, constant.IsHistory: false, constant.IsUnreadCount: false}
+	if len(options) != len(want) {
+		t.Fatalf("options = %v, want %v", options, want)
+	}
+	for key, value := range want {
+		if v, ok := options[key]; !ok || v != value {
+			t.Errorf("options[%q] = %v (set %v), want %v", key, v, ok, value)
+		}
+	}
+
+	options = map[string]bool{constant.IsHistory: false}
+	applyDefaultSendOptions(withTestConfig(&sdk_struct.IMConfig{}), options)
+	if len(options) != 1 || options[constant.IsHistory] {
+		t.Errorf("without defaults options = %v, want them unchanged", options)
+	}
+}
+
+func TestUpdateConvertedMsgContent(t *testing.T) {
+	c, _, _ := newTestConversation(t)
+	c.DataDir = t.TempDir() + "/"
+	conversationID := "si_peer_a_test_user"
+	failed := newTestTextMsg("text", testLoginUserID, 0, 1, true)
+	failed.Status = constant.MsgStatusSendFailed
+	failed.Content = `{"content":"` + strings.Repeat("a", 11) + `"}`
+	insertTestMsgs(t, c, &model_struct.LocalConversation{ConversationID: conversationID, ConversationType: constant.SingleChatType}, failed)
+
+	// the resend is converted to a file message, the stored row follows it
+	s := LocalChatLogToMsgStruct(failed)
+	ctx := withTestConfig(&sdk_struct.IMConfig{MaxMsgContentSize: 10, ConvertOversizedTextToFile: true})
+	if err := c.checkMsgContentSize(ctx, s, true); err != nil {
+		t.Fatal(err)
+	}
+	if err := c.updateConvertedMsgContent(ctx, conversationID, failed, s); err != nil {
+		t.Fatal(err)
+	}
+	stored, err := c.db.GetMessage(ctx, conversationID, "text")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if stored.ContentType != constant.File || stored.Content != utils.StructToJsonString(s.FileElem) {
+		t.Fatalf("stored message = %d %s, want the file message", stored.ContentType, stored.Content)
+	}
+	if stored.Status != constant.MsgStatusSendFailed {
+		t.Errorf("stored status = %d, the other columns should be kept", stored.Status)
+	}
+
+	// a resend that was not converted leaves the row as it is
+	if err := c.updateConvertedMsgContent(ctx, conversationID, stored, LocalChatLogToMsgStruct(stored)); err != nil {
+		t.Fatal(err)
+	}
+}
+
+func TestGetConversationRecvMessageOpt(t *testing.T) {
+	ctx := context.Background()
+	c, _, _ := newTestConversation(t)
+	if err := c.db.BatchInsertConversationList(ctx, []*model_struct.LocalConversation{
+		{ConversationID: "si_peer_a_test_user", RecvMsgOpt: constant.ReceiveMessage},
+		{ConversationID: "si_peer_b_test_user", RecvMsgOpt: constant.NotReceiveMessage},
+	}); err != nil {
+		t.Fatal(err)
+	}
+
+	opts, err := c.GetConversationRecvMessageOpt(ctx, []string{"si_peer_a_test_user", "si_not_exist", "si_peer_b_test_user"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	got := make(map[string]int32)
+	for _, opt := range opts {
+		got[opt.ConversationID] = opt.RecvMsgOpt
+	}
+	if len(opts) != 2 || len(got) != 2 {
+		t.Fatalf("opts = %v, want the two existing conversations only", got)
+	}
+	if opt, ok := got["si_peer_a_test_user"]; !ok || opt != constant.ReceiveMessage {
+		t.Errorf("si_peer_a_test_user opt = %d (found %v), want %d", opt, ok, constant.ReceiveMessage)
+	}
+	if opt, ok := got["si_peer_b_test_user"]; !ok || opt != constant.NotReceiveMessage {
+		t.Errorf("si_peer_b_test_user opt = %d (found %v), want %d", opt, ok, constant.NotReceiveMessage)
+	}
+
+	opts, err = c.GetConversationRecvMessageOpt(ctx, []string{"si_not_exist"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if opts == nil || len(opts) != 0 {
+		t.Errorf("opts of a missing conversation = %v, want an empty list", opts)
+	}
+}
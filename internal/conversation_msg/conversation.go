@@ -247,10 +247,11 @@ func (c *Conversation) fetchMessagesWithGapCheck(ctx context.Context, conversati
 
 func (c *Conversation) LocalChatLog2MsgStruct(list []*model_struct.LocalChatLog) []*sdk_struct.MsgStruct {
 	messageList := make([]*sdk_struct.MsgStruct, 0, len(list))
+	now := time.Now().UnixMilli()
 	for _, v := range list {
 		temp := LocalChatLogToMsgStruct(v)
 
-		if temp.AttachedInfoElem.IsPrivateChat && temp.SendTime+int64(temp.AttachedInfoElem.BurnDuration) < time.Now().Unix() {
+		if isBurned(temp.AttachedInfoElem, now) {
 			continue
 		}
 		messageList = append(messageList, temp)
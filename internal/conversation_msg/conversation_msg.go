@@ -6,6 +6,7 @@ import (
 	"errors"
 	"fmt"
 	"math"
+	"os"
 	"sync"
 
 	"github.com/openimsdk/openim-sdk-core/v3/pkg/api"
@@ -65,6 +66,7 @@ type Conversation struct {
 	user                        *user.User
 	file                        *file.File
 	cache                       *cache.Cache[string, *model_struct.LocalConversation]
+	pendingThumbnails           *cache.Cache[string, string]
 	maxSeqRecorder              MaxSeqRecorder
 	messagePullForwardEndSeqMap *cache.ConversationSeqContextCache
 	messagePullReverseEndSeqMap *cache.ConversationSeqContextCache
@@ -76,7 +78,8 @@ type Conversation struct {
 
 	startTime time.Time
 
-	typing *typing
+	typing      *typing
+	msgDestruct *msgDestruct
 }
 
 func (c *Conversation) ConversationEventQueue() chan common.Cmd2Value {
@@ -132,11 +135,29 @@ func NewConversation(
 		progress:                    0,
 	}
 	n.typing = newTyping(n)
+	n.msgDestruct = newMsgDestruct()
 	n.initSyncer()
 	n.cache = cache.NewCache[string, *model_struct.LocalConversation]()
+	n.pendingThumbnails = cache.NewCache[string, string]()
 	return n
 }
 
+// ClearMemoryCaches drops the cached conversations and the video thumbnails copied for messages
+// that have not been sent yet. A thumbnail copy is only removed when its source file still exists,
+// so that a later send or resend can read the source instead.
+func (c *Conversation) ClearMemoryCaches(ctx context.Context) {
+	c.cache.DeleteAll()
+	for _, snapshotPath := range c.pendingThumbnails.RangeAll() {
+		if !utils.FileExist(snapshotPath) {
+			continue
+		}
+		if err := os.Remove(utils.FileTmpPath(snapshotPath, c.DataDir)); err != nil && !os.IsNotExist(err) {
+			log.ZWarn(ctx, "remove thumbnail copy failed", err, "snapshotPath", snapshotPath)
+		}
+	}
+	c.pendingThumbnails.DeleteAll()
+}
+
 func (c *Conversation) initSyncer() {
 	c.conversationSyncer = syncer.New2[*model_struct.LocalConversation, pbConversation.GetOwnerConversationResp, string](
 		syncer.WithInsert[*model_struct.LocalConversation, pbConversation.GetOwnerConversationResp, string](func(ctx context.Context, value *model_struct.LocalConversation) error {
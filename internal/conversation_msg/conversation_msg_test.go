@@ -0,0 +1,110 @@
+//go:build !js
+// +build !js
+
+package conversation_msg
+
+import (
+	"context"
+	"testing"
+
+	"github.com/openimsdk/openim-sdk-core/v3/open_im_sdk_callback"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/common"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/utils"
+	"github.com/openimsdk/openim-sdk-core/v3/sdk_struct"
+)
+
+const testLoginUserID = "test_user"
+
+// testConversationListener records the callbacks used by the tests, any other callback panics.
+type testConversationListener struct {
+	open_im_sdk_callback.OnConversationListener
+	changed     []string
+	totalUnread []int32
+}
+
+func (l *testConversationListener) OnConversationChanged(conversationList string) {
+	l.changed = append(l.changed, conversationList)
+}
+
+func (l *testConversationListener) OnTotalUnreadMessageCountChanged(totalUnreadCount int32) {
+	l.totalUnread = append(l.totalUnread, totalUnreadCount)
+}
+
+type testMsgListener struct {
+	open_im_sdk_callback.OnAdvancedMsgListener
+	deleted []string
+}
+
+func (l *testMsgListener) OnMsgDeleted(message string) {
+	l.deleted = append(l.deleted, message)
+}
+
+// newTestConversation returns a Conversation backed by a fresh database in a temporary directory.
+func newTestConversation(t *testing.T) (*Conversation, *testConversationListener, *testMsgListener) {
+	t.Helper()
+	database, err := db.NewDataBase(context.Background(), testLoginUserID, t.TempDir(), 1)
+	if err != nil {
+		t.Fatal(err)
+	}
+	t.Cleanup(func() { _ = database.Close(context.Background()) })
+	conversationListener := &testConversationListener{}
+	msgListener := &testMsgListener{}
+	c := &Conversation{
+		db:                   database,
+		loginUserID:          testLoginUserID,
+		ConversationListener: func() open_im_sdk_callback.OnConversationListener { return conversationListener },
+		msgListener:          func() open_im_sdk_callback.OnAdvancedMsgListener { return msgListener },
+		msgDestruct:          newMsgDestruct(),
+	}
+	// without the event loop running, dispatched conversation events are drained so that they never block
+	c.conversationEventQueue = make(chan common.Cmd2Value, 100)
+	done := make(chan struct{})
+	go func() {
+		for {
+			select {
+			case <-c.conversationEventQueue:
+			case <-done:
+				return
+			}
+		}
+	}()
+	t.Cleanup(func() { close(done) })
+	return c, conversationListener, msgListener
+}
+
+// insertTestMsgs stores msgs in the conversation and points its latest message at the newest one.
+func insertTestMsgs(t *testing.T, c *Conversation, conversation *model_struct.LocalConversation, msgs ...*model_struct.LocalChatLog) {
+	t.Helper()
+	ctx := context.Background()
+	if err := c.db.BatchInsertMessageList(ctx, conversation.ConversationID, msgs); err != nil {
+		t.Fatal(err)
+	}
+	latest := msgs[0]
+	for _, msg := range msgs {
+		if msg.SendTime > latest.SendTime {
+			latest = msg
+		}
+	}
+	conversation.LatestMsg = utils.StructToJsonString(LocalChatLogToMsgStruct(latest))
+	conversation.LatestMsgSendTime = latest.SendTime
+	if err := c.db.InsertConversation(ctx, conversation); err != nil {
+		t.Fatal(err)
+	}
+}
+
+func getTestConversation(t *testing.T, c *Conversation, conversationID string) (*model_struct.LocalConversation, *sdk_struct.MsgStruct) {
+	t.Helper()
+	conversation, err := c.db.GetConversation(context.Background(), conversationID)
+	if err != nil {
+		t.Fatal(err)
+	}
+	latestMsg := &sdk_struct.MsgStruct{}
+	if conversation.LatestMsg != "" {
+		if err := utils.JsonStringToStruct(conversation.LatestMsg, latestMsg); err != nil {
+			t.Fatal(err)
+		}
+	}
+	return conversation, latestMsg
+}
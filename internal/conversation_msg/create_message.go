@@ -5,11 +5,13 @@ import (
 	"errors"
 
 	"github.com/openimsdk/openim-sdk-core/v3/pkg/constant"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/content_type"
 	"github.com/openimsdk/tools/log"
 
 	"os"
 	"path/filepath"
 	"strings"
+	"unicode/utf16"
 
 	"github.com/openimsdk/openim-sdk-core/v3/pkg/sdkerrs"
 	"github.com/openimsdk/openim-sdk-core/v3/pkg/utils"
@@ -26,6 +28,9 @@ func (c *Conversation) CreateTextMessage(ctx context.Context, text string) (*sdk
 	return &s, nil
 }
 func (c *Conversation) CreateAdvancedTextMessage(ctx context.Context, text string, messageEntities []*sdk_struct.MessageEntity) (*sdk_struct.MsgStruct, error) {
+	if err := checkMessageEntities(text, messageEntities); err != nil {
+		return nil, err
+	}
 	s := sdk_struct.MsgStruct{}
 	err := c.initBasicInfo(ctx, &s, constant.UserMsgType, constant.AdvancedText)
 	if err != nil {
@@ -38,6 +43,26 @@ func (c *Conversation) CreateAdvancedTextMessage(ctx context.Context, text strin
 	return &s, nil
 }
 
+// checkMessageEntities rejects entities whose range is not inside text or splits a surrogate pair,
+// offsets and lengths count UTF-16 code units. Only messages created by this client are checked,
+// entities of received or stored messages are kept as they are.
+func checkMessageEntities(text string, messageEntities []*sdk_struct.MessageEntity) error {
+	units := utf16.Encode([]rune(text))
+	textLen := int32(len(units))
+	isLowSurrogate := func(i int32) bool {
+		return i < textLen && units[i] >= 0xDC00 && units[i] <= 0xDFFF
+	}
+	for _, entity := range messageEntities {
+		if entity == nil || !entity.InRange(textLen) {
+			return sdkerrs.ErrArgs.WrapMsg("message entity out of text range", "entity", entity, "textLen", textLen)
+		}
+		if isLowSurrogate(entity.Offset) || isLowSurrogate(entity.Offset+entity.Length) {
+			return sdkerrs.ErrArgs.WrapMsg("message entity splits a surrogate pair", "entity", entity)
+		}
+	}
+	return nil
+}
+
 func (c *Conversation) CreateTextAtMessage(ctx context.Context, text string, userIDList []string, usersInfo []*sdk_struct.AtInfo, qs *sdk_struct.MsgStruct) (*sdk_struct.MsgStruct, error) {
 	if text == "" {
 		return nil, errors.New("text can not be empty")
@@ -112,6 +137,9 @@ func (c *Conversation) CreateQuoteMessage(ctx context.Context, text string, qs *
 
 }
 func (c *Conversation) CreateAdvancedQuoteMessage(ctx context.Context, text string, qs *sdk_struct.MsgStruct, messageEntities []*sdk_struct.MessageEntity) (*sdk_struct.MsgStruct, error) {
+	if err := checkMessageEntities(text, messageEntities); err != nil {
+		return nil, err
+	}
 	s := sdk_struct.MsgStruct{}
 	err := c.initBasicInfo(ctx, &s, constant.UserMsgType, constant.Quote)
 	if err != nil {
@@ -192,6 +220,7 @@ func (c *Conversation) CreateVideoMessageFromFullPath(ctx context.Context, video
 		s.VideoElem.SnapshotHeight = imageInfo.Height
 		s.VideoElem.SnapshotWidth = imageInfo.Width
 		s.VideoElem.SnapshotSize = imageInfo.Size
+		c.pendingThumbnails.Store(s.ClientMsgID, snapshotFullPath)
 	}
 	return &s, nil
 
@@ -214,10 +243,14 @@ func (c *Conversation) CreateFileMessageFromFullPath(ctx context.Context, fileFu
 		//log.Error("internal", "get file Attributes error", err.Error())
 		return nil, err
 	}
+	if fileName == "" {
+		fileName = filepath.Base(fileFullPath)
+	}
 	s.FileElem = &sdk_struct.FileElem{
 		FilePath: fileFullPath,
 		FileName: fileName,
 		FileSize: fi.Size(),
+		FileType: content_type.GetType(filepath.Ext(fileName), filepath.Ext(fileFullPath)),
 	}
 	return &s, nil
 }
@@ -0,0 +1,92 @@
+//go:build !js
+// +build !js
+
+package conversation_msg
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/openimsdk/openim-sdk-core/v3/internal/user"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/constant"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/sdkerrs"
+	"github.com/openimsdk/openim-sdk-core/v3/sdk_struct"
+)
+
+func TestCheckMessageEntities(t *testing.T) {
+	tests := []struct {
+		name   string
+		text   string
+		entity *sdk_struct.MessageEntity
+		valid  bool
+	}{
+		{"ascii", "hello", &sdk_struct.MessageEntity{Type: "bold", Offset: 0, Length: 5}, true},
+		{"ends at the text end", "hello", &sdk_struct.MessageEntity{Offset: 2, Length: 3}, true},
+		{"empty at the text end", "hello", &sdk_struct.MessageEntity{Offset: 5, Length: 0}, true},
+		{"past the text end", "hello", &sdk_struct.MessageEntity{Offset: 2, Length: 4}, false},
+		{"negative offset", "hello", &sdk_struct.MessageEntity{Offset: -1, Length: 2}, false},
+		{"negative length", "hello", &sdk_struct.MessageEntity{Offset: 1, Length: -1}, false},
+		{"nil", "hello", nil, false},
+		// "😀" is one rune but two UTF-16 code units
+		{"emoji", "a😀b", &sdk_struct.MessageEntity{Offset: 1, Length: 2}, true},
+		{"emoji ends at the text end", "a😀", &sdk_struct.MessageEntity{Offset: 0, Length: 3}, true},
+		{"emoji counted as one unit", "a😀", &sdk_struct.MessageEntity{Offset: 0, Length: 4}, false},
+		{"ends inside a surrogate pair", "a😀b", &sdk_struct.MessageEntity{Offset: 0, Length: 2}, false},
+		{"starts inside a surrogate pair", "a😀b", &sdk_struct.MessageEntity{Offset: 2, Length: 2}, false},
+		{"multi-byte non-surrogate", "héllo", &sdk_struct.MessageEntity{Offset: 1, Length: 4}, true},
+		{"offset plus length overflows", "hello", &sdk_struct.MessageEntity{Offset: 1, Length: 1<<31 - 1}, false},
+	}
+	for _, tt := range tests {
+		err := checkMessageEntities(tt.text, []*sdk_struct.MessageEntity{tt.entity})
+		if tt.valid && err != nil {
+			t.Errorf("%s: err = %v, want valid", tt.name, err)
+		}
+		if !tt.valid && !sdkerrs.ErrArgs.Is(err) {
+			t.Errorf("%s: err = %v, want ErrArgs", tt.name, err)
+		}
+	}
+	if err := checkMessageEntities("hello", nil); err != nil {
+		t.Errorf("no entity: err = %v", err)
+	}
+}
+
+func TestCreateFileMessageFromFullPath(t *testing.T) {
+	c := &Conversation{DataDir: t.TempDir() + "/", loginUserID: testLoginUserID, user: user.NewUser(nil)}
+	c.user.UserCache().Store(testLoginUserID, &model_struct.LocalUser{UserID: testLoginUserID})
+	dir := t.TempDir()
+	pdf := filepath.Join(dir, "report.pdf")
+	unknown := filepath.Join(dir, "data.unknownext")
+	for _, path := range []string{pdf, unknown} {
+		if err := os.WriteFile(path, []byte("file"), 0644); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	tests := []struct {
+		name         string
+		fullPath     string
+		fileName     string
+		wantFileName string
+		wantFileType string
+	}{
+		{"empty file name", pdf, "", "report.pdf", "application/pdf"},
+		{"file name type first", pdf, "report.txt", "report.txt", "text/plain"},
+		{"file name without extension", pdf, "report", "report", "application/pdf"},
+		{"unknown extension", unknown, "", "data.unknownext", ""},
+	}
+	for _, tt := range tests {
+		s, err := c.CreateFileMessageFromFullPath(context.Background(), tt.fullPath, tt.fileName)
+		if err != nil {
+			t.Fatalf("%s: %v", tt.name, err)
+		}
+		if s.ContentType != constant.File || s.FileElem == nil {
+			t.Fatalf("%s: not a file message: %+v", tt.name, s)
+		}
+		if s.FileElem.FileName != tt.wantFileName || s.FileElem.FileType != tt.wantFileType || s.FileElem.FileSize != 4 {
+			t.Errorf("%s: file = %q %q %d, want %q %q 4", tt.name, s.FileElem.FileName, s.FileElem.FileType, s.FileElem.FileSize, tt.wantFileName, tt.wantFileType)
+		}
+	}
+}
@@ -69,6 +69,9 @@ func (c *Conversation) clearConversationAndDeleteAllMsg(ctx context.Context, con
 	if err != nil {
 		return err
 	}
+	if err := c.db.DeleteConversationStarredMessages(ctx, conversationID); err != nil {
+		log.ZWarn(ctx, "DeleteConversationStarredMessages err", err, "conversationID", conversationID)
+	}
 	log.ZDebug(ctx, "reset conversation", "conversationID", conversationID)
 	err = f(ctx, conversationID)
 	if err != nil {
@@ -153,6 +156,9 @@ func (c *Conversation) deleteMessageFromLocal(ctx context.Context, conversationI
 	if err := c.db.UpdateColumnsMessage(ctx, conversationID, clientMsgID, map[string]interface{}{"status": constant.MsgStatusHasDeleted}); err != nil {
 		return err
 	}
+	if err := c.db.DeleteStarredMessage(ctx, conversationID, clientMsgID); err != nil {
+		log.ZWarn(ctx, "DeleteStarredMessage err", err, "conversationID", conversationID, "clientMsgID", clientMsgID)
+	}
 
 	if !s.IsRead && s.SendID != c.loginUserID {
 		if err := c.db.DecrConversationUnreadCount(ctx, conversationID, 1); err != nil {
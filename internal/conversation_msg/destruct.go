@@ -0,0 +1,242 @@
+// Copyright © 2023 OpenIM SDK. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+package conversation_msg
+
+import (
+	"context"
+	"fmt"
+	"runtime/debug"
+	"sync"
+	"time"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/common"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/constant"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/utils"
+	"github.com/openimsdk/tools/log"
+)
+
+const (
+	msgDestructInterval  = time.Minute * 10
+	msgDestructBatchSize = 200
+)
+
+// msgDestruct purges messages of conversations with is_msg_destruct set once they are older than msg_destruct_time,
+// and burns read messages of private chats.
+type msgDestruct struct {
+	trigger chan struct{}
+
+	lock    sync.Mutex
+	running bool
+	// burnTimers are the pending burn timers of private chats, they belong to the login session
+	// and are stopped when the destruct loop ends.
+	burnTimers map[*time.Timer]struct{}
+}
+
+func newMsgDestruct() *msgDestruct {
+	return &msgDestruct{trigger: make(chan struct{}, 1), burnTimers: make(map[*time.Timer]struct{})}
+}
+
+func (m *msgDestruct) start() {
+	m.lock.Lock()
+	defer m.lock.Unlock()
+	m.running = true
+}
+
+// stop stops the pending burn timers, the messages they were waiting for are burned by the first run
+// of the next login.
+func (m *msgDestruct) stop() {
+	m.lock.Lock()
+	defer m.lock.Unlock()
+	m.running = false
+	for timer := range m.burnTimers {
+		timer.Stop()
+		delete(m.burnTimers, timer)
+	}
+}
+
+// triggerMsgDestruct asks the destruct loop to run now, it is called after the connection is established
+// and when a burn timer of a private chat expires.
+func (c *Conversation) triggerMsgDestruct() {
+	select {
+	case c.msgDestruct.trigger <- struct{}{}:
+	default:
+	}
+}
+
+// DoMsgDestruct runs the destruct loop until ctx is done.
+func (c *Conversation) DoMsgDestruct(ctx context.Context) {
+	c.msgDestruct.start()
+	defer c.msgDestruct.stop()
+	ticker := time.NewTicker(msgDestructInterval)
+	defer ticker.Stop()
+	for {
+		c.safeDestructMsgs(ctx)
+		select {
+		case <-ctx.Done():
+			log.ZInfo(ctx, "DoMsgDestruct done")
+			return
+		case <-ticker.C:
+		case <-c.msgDestruct.trigger:
+		}
+	}
+}
+
+// safeDestructMsgs runs one round of destructMsgs, a panic only ends this round and not the loop.
+func (c *Conversation) safeDestructMsgs(ctx context.Context) {
+	defer func() {
+		if r := recover(); r != nil {
+			log.ZWarn(ctx, "destructMsgs panic", nil, "panic info", fmt.Sprintf("panic: %+v\n%s", r, debug.Stack()))
+		}
+	}()
+	if err := c.destructMsgs(ctx); err != nil {
+		log.ZWarn(ctx, "destructMsgs err", err)
+	}
+}
+
+func (c *Conversation) destructMsgs(ctx context.Context) error {
+	conversations, err := c.db.GetAllConversations(ctx)
+	if err != nil {
+		return err
+	}
+	for _, conversation := range conversations {
+		if conversation.IsPrivateChat {
+			if err := c.burnPrivateChatMsgs(ctx, conversation); err != nil {
+				log.ZWarn(ctx, "burnPrivateChatMsgs err", err, "conversationID", conversation.ConversationID)
+			}
+		}
+		if !conversation.IsMsgDestruct || conversation.MsgDestructTime <= 0 {
+			continue
+		}
+		if err := c.destructConversationMsgs(ctx, conversation); err != nil {
+			log.ZWarn(ctx, "destructConversationMsgs err", err, "conversationID", conversation.ConversationID)
+		}
+	}
+	return nil
+}
+
+// destructConversationMsgs purges the sent messages older than msg_destruct_time, messages still sending or
+// failed to send are kept so that they can be resent. It holds conversationSyncMutex like the sync and
+// read paths that write the same conversation row.
+func (c *Conversation) destructConversationMsgs(ctx context.Context, conversation *model_struct.LocalConversation) error {
+	c.conversationSyncMutex.Lock()
+	defer c.conversationSyncMutex.Unlock()
+	now := time.Now().UnixMilli()
+	destructTime := now - conversation.MsgDestructTime*1000
+	var (
+		startTime, startSeq = destructTime, int64(0)
+		startClientMsgID    string
+		deleted             []*model_struct.LocalChatLog
+	)
+	for {
+		msgs, err := c.db.GetMessageList(ctx, conversation.ConversationID, msgDestructBatchSize, startTime, startSeq, startClientMsgID, false)
+		if err != nil {
+			return err
+		}
+		if len(msgs) == 0 {
+			break
+		}
+		var (
+			clientMsgIDs []string
+			sent         []*model_struct.LocalChatLog
+		)
+		for _, msg := range msgs {
+			if msg.Status != constant.MsgStatusSendSuccess {
+				continue
+			}
+			clientMsgIDs = append(clientMsgIDs, msg.ClientMsgID)
+			sent = append(sent, msg)
+		}
+		if len(clientMsgIDs) > 0 {
+			if err := c.db.DeleteConversationMsgs(ctx, conversation.ConversationID, clientMsgIDs); err != nil {
+				return err
+			}
+			deleted = append(deleted, sent...)
+		}
+		if len(msgs) < msgDestructBatchSize {
+			break
+		}
+		last := msgs[len(msgs)-1]
+		startTime, startSeq, startClientMsgID = last.SendTime, last.Seq, last.ClientMsgID
+	}
+	// recorded like latest_msg_destruct_time on the server
+	if err := c.db.UpdateColumnsConversation(ctx, conversation.ConversationID, map[string]interface{}{"latest_msg_destruct_time": now}); err != nil {
+		return err
+	}
+	if len(deleted) == 0 {
+		return nil
+	}
+	log.ZInfo(ctx, "destruct msgs", "conversationID", conversation.ConversationID, "count", len(deleted), "destructTime", destructTime)
+	return c.msgsPurged(ctx, conversation, deleted)
+}
+
+// msgsPurged repairs the unread count and latest message of a conversation after messages were removed
+// from the local store without the user asking for it, and notifies the listeners.
+// The caller holds conversationSyncMutex, the conversation is read again as it may have changed before the lock was taken.
+func (c *Conversation) msgsPurged(ctx context.Context, conversation *model_struct.LocalConversation, deleted []*model_struct.LocalChatLog) error {
+	conversation, err := c.db.GetConversation(ctx, conversation.ConversationID)
+	if err != nil {
+		return err
+	}
+	var (
+		unreadCount  int64
+		latestMsgDel bool
+	)
+	latestMsgClientID := c.getConversationLatestMsgClientID(conversation.LatestMsg)
+	clientMsgIDs := make([]string, 0, len(deleted))
+	for _, msg := range deleted {
+		if !msg.IsRead && msg.SendID != c.loginUserID {
+			unreadCount++
+		}
+		if msg.ClientMsgID == latestMsgClientID {
+			latestMsgDel = true
+		}
+		clientMsgIDs = append(clientMsgIDs, msg.ClientMsgID)
+	}
+	if err := c.db.DeleteStarredMessages(ctx, conversation.ConversationID, clientMsgIDs); err != nil {
+		log.ZWarn(ctx, "DeleteStarredMessages err", err, "conversationID", conversation.ConversationID)
+	}
+	if unreadCount > 0 {
+		if err := c.db.DecrConversationUnreadCount(ctx, conversation.ConversationID, unreadCount); err != nil {
+			log.ZWarn(ctx, "DecrConversationUnreadCount err", err, "conversationID", conversation.ConversationID)
+		}
+	}
+	if latestMsgDel {
+		latestMsgStr := ""
+		latestMsgSendTime := conversation.LatestMsgSendTime
+		msgs, err := c.db.GetLatestActiveMessage(ctx, conversation.ConversationID, false)
+		if err != nil {
+			return err
+		}
+		if len(msgs) > 0 {
+			latestMsg := LocalChatLogToMsgStruct(msgs[0])
+			latestMsgStr = utils.StructToJsonString(latestMsg)
+			latestMsgSendTime = latestMsg.SendTime
+		}
+		if err := c.db.UpdateColumnsConversation(ctx, conversation.ConversationID, map[string]interface{}{"latest_msg": latestMsgStr, "latest_msg_send_time": latestMsgSendTime}); err != nil {
+			return err
+		}
+	}
+	if unreadCount > 0 || latestMsgDel {
+		c.doUpdateConversation(common.Cmd2Value{Value: common.UpdateConNode{ConID: conversation.ConversationID, Action: constant.ConChange, Args: []string{conversation.ConversationID}}, Ctx: ctx})
+	}
+	if unreadCount > 0 {
+		c.doUpdateConversation(common.Cmd2Value{Value: common.UpdateConNode{Action: constant.TotalUnreadMessageChanged}, Ctx: ctx})
+	}
+	for _, msg := range deleted {
+		c.msgListener().OnMsgDeleted(utils.StructToJsonString(msg))
+	}
+	return nil
+}
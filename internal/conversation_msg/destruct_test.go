@@ -0,0 +1,138 @@
+//go:build !js
+// +build !js
+
+package conversation_msg
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/constant"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+)
+
+func newTestTextMsg(clientMsgID, sendID string, seq, sendTime int64, isRead bool) *model_struct.LocalChatLog {
+	return &model_struct.LocalChatLog{
+		ClientMsgID: clientMsgID,
+		SendID:      sendID,
+		RecvID:      testLoginUserID,
+		SessionType: constant.SingleChatType,
+		ContentType: constant.Text,
+		Content:     `{"content":"` + clientMsgID + `"}`,
+		Seq:         seq,
+		SendTime:    sendTime,
+		IsRead:      isRead,
+		Status:      constant.MsgStatusSendSuccess,
+	}
+}
+
+func TestDestructConversationMsgs(t *testing.T) {
+	ctx := context.Background()
+	c, conversationListener, msgListener := newTestConversation(t)
+	now := time.Now().UnixMilli()
+
+	// some messages expired, the newest one is kept
+	partly := &model_struct.LocalConversation{
+		ConversationID:   "si_peer_a_test_user",
+		ConversationType: constant.SingleChatType,
+		UserID:           "peer_a",
+		UnreadCount:      2,
+		IsMsgDestruct:    true,
+		MsgDestructTime:  60,
+	}
+	insertTestMsgs(t, c, partly,
+		newTestTextMsg("a1", "peer_a", 1, now-2*time.Hour.Milliseconds(), false),
+		newTestTextMsg("a2", testLoginUserID, 2, now-90*time.Second.Milliseconds(), true),
+		newTestTextMsg("a3", "peer_a", 3, now-10*time.Second.Milliseconds(), false),
+	)
+	// every message expired, including the latest one
+	all := &model_struct.LocalConversation{
+		ConversationID:   "si_peer_b_test_user",
+		ConversationType: constant.SingleChatType,
+		UserID:           "peer_b",
+		UnreadCount:      1,
+		IsMsgDestruct:    true,
+		MsgDestructTime:  60,
+	}
+	insertTestMsgs(t, c, all, newTestTextMsg("b1", "peer_b", 1, now-time.Hour.Milliseconds(), false))
+
+	for _, conversation := range []*model_struct.LocalConversation{partly, all} {
+		if err := c.destructConversationMsgs(ctx, conversation); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	msgs, err := c.db.GetMessageList(ctx, partly.ConversationID, 10, 0, 0, "", false)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(msgs) != 1 || msgs[0].ClientMsgID != "a3" {
+		t.Fatalf("expected only a3 to be kept, got %d messages", len(msgs))
+	}
+	conversation, latestMsg := getTestConversation(t, c, partly.ConversationID)
+	if conversation.UnreadCount != 1 {
+		t.Errorf("unread count = %d, want 1", conversation.UnreadCount)
+	}
+	if latestMsg.ClientMsgID != "a3" {
+		t.Errorf("latest msg = %q, want a3", latestMsg.ClientMsgID)
+	}
+	if conversation.LatestMsgDestructTime < now {
+		t.Errorf("latest msg destruct time = %d, want >= %d", conversation.LatestMsgDestructTime, now)
+	}
+
+	msgs, err = c.db.GetMessageList(ctx, all.ConversationID, 10, 0, 0, "", false)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(msgs) != 0 {
+		t.Fatalf("expected every message to be purged, got %d", len(msgs))
+	}
+	conversation, _ = getTestConversation(t, c, all.ConversationID)
+	if conversation.UnreadCount != 0 {
+		t.Errorf("unread count = %d, want 0", conversation.UnreadCount)
+	}
+	if conversation.LatestMsg != "" {
+		t.Errorf("latest msg = %q, want empty", conversation.LatestMsg)
+	}
+
+	if len(msgListener.deleted) != 3 {
+		t.Errorf("OnMsgDeleted called %d times, want 3", len(msgListener.deleted))
+	}
+	if len(conversationListener.totalUnread) == 0 || conversationListener.totalUnread[len(conversationListener.totalUnread)-1] != 1 {
+		t.Errorf("total unread notifications = %v, want the last one to be 1", conversationListener.totalUnread)
+	}
+}
+
+func TestDestructConversationMsgsKeepsUnsent(t *testing.T) {
+	ctx := context.Background()
+	c, _, msgListener := newTestConversation(t)
+	now := time.Now().UnixMilli()
+
+	conversation := &model_struct.LocalConversation{
+		ConversationID:   "si_peer_a_test_user",
+		ConversationType: constant.SingleChatType,
+		UserID:           "peer_a",
+		IsMsgDestruct:    true,
+		MsgDestructTime:  60,
+	}
+	sending := newTestTextMsg("sending", testLoginUserID, 0, now-3*time.Hour.Milliseconds(), true)
+	sending.Status = constant.MsgStatusSending
+	failed := newTestTextMsg("failed", testLoginUserID, 0, now-2*time.Hour.Milliseconds(), true)
+	failed.Status = constant.MsgStatusSendFailed
+	insertTestMsgs(t, c, conversation, sending, failed, newTestTextMsg("sent", testLoginUserID, 1, now-time.Hour.Milliseconds(), true))
+
+	if err := c.destructConversationMsgs(ctx, conversation); err != nil {
+		t.Fatal(err)
+	}
+	msgs, err := c.db.GetMessageList(ctx, conversation.ConversationID, 10, 0, 0, "", false)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(msgs) != 2 || msgs[0].ClientMsgID != "failed" || msgs[1].ClientMsgID != "sending" {
+		t.Fatalf("expected the sending and failed messages to be kept, got %d messages", len(msgs))
+	}
+	if len(msgListener.deleted) != 1 {
+		t.Errorf("OnMsgDeleted called %d times, want 1", len(msgListener.deleted))
+	}
+}
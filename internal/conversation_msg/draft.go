@@ -0,0 +1,58 @@
+// Copyright © 2023 OpenIM SDK. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+package conversation_msg
+
+import (
+	"context"
+	"encoding/json"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/common"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/constant"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/sdk_params_callback"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/utils"
+)
+
+// SetConversationStructuredDraft keeps the text of the draft in draft_text, so it is what GetConversation and the
+// conversation callbacks show as the draft, and the whole draft as json in structured_draft.
+func (c *Conversation) SetConversationStructuredDraft(ctx context.Context, conversationID string, draft *sdk_params_callback.StructuredDraft) error {
+	if draft == nil || (draft.Text == "" && len(draft.Attachments) == 0 && draft.QuoteClientMsgID == "") {
+		return c.SetConversationDraft(ctx, conversationID, "")
+	}
+	if err := c.db.SetConversationStructuredDraftDB(ctx, conversationID, draft.Text, utils.StructToJsonString(draft)); err != nil {
+		return err
+	}
+	_ = common.DispatchUpdateConversation(ctx, common.UpdateConNode{Action: constant.ConChange, Args: []string{conversationID}}, c.ConversationEventQueue())
+	return nil
+}
+
+// GetConversationStructuredDraft returns the draft of a conversation, a plain text draft is returned as its Text.
+func (c *Conversation) GetConversationStructuredDraft(ctx context.Context, conversationID string) (*sdk_params_callback.StructuredDraft, error) {
+	conversation, err := c.db.GetConversation(ctx, conversationID)
+	if err != nil {
+		return nil, err
+	}
+	return parseStructuredDraft(conversation), nil
+}
+
+// parseStructuredDraft never fails, a conversation without a valid structured draft has a plain text one
+// written by SetConversationDraft.
+func parseStructuredDraft(conversation *model_struct.LocalConversation) *sdk_params_callback.StructuredDraft {
+	var draft sdk_params_callback.StructuredDraft
+	if conversation.StructuredDraft == "" || json.Unmarshal([]byte(conversation.StructuredDraft), &draft) != nil {
+		return &sdk_params_callback.StructuredDraft{Text: conversation.DraftText}
+	}
+	return &draft
+}
@@ -0,0 +1,82 @@
+//go:build !js
+// +build !js
+
+package conversation_msg
+
+import (
+	"context"
+	"testing"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/constant"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/sdk_params_callback"
+	"github.com/openimsdk/openim-sdk-core/v3/sdk_struct"
+)
+
+func TestStructuredDraft(t *testing.T) {
+	ctx := context.Background()
+	c, _, _ := newTestConversation(t)
+	conversationID := "si_peer_a_test_user"
+	if err := c.db.InsertConversation(ctx, &model_struct.LocalConversation{ConversationID: conversationID, ConversationType: constant.SingleChatType}); err != nil {
+		t.Fatal(err)
+	}
+
+	draft := &sdk_params_callback.StructuredDraft{
+		Text:             "hello",
+		Attachments:      []*sdk_struct.MsgStruct{{ClientMsgID: "image", ContentType: constant.Picture}},
+		QuoteClientMsgID: "quoted",
+		Ex:               "ex",
+	}
+	if err := c.SetConversationStructuredDraft(ctx, conversationID, draft); err != nil {
+		t.Fatal(err)
+	}
+	got, err := c.GetConversationStructuredDraft(ctx, conversationID)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got.Text != draft.Text || got.QuoteClientMsgID != draft.QuoteClientMsgID || got.Ex != draft.Ex ||
+		len(got.Attachments) != 1 || got.Attachments[0].ClientMsgID != "image" {
+		t.Fatalf("round trip got %+v, want %+v", got, draft)
+	}
+	// readers of draft_text only see the text
+	conversation, _ := getTestConversation(t, c, conversationID)
+	if conversation.DraftText != "hello" || conversation.DraftTextTime == 0 {
+		t.Errorf("draft text = %q (time %d), want the plain text of the draft", conversation.DraftText, conversation.DraftTextTime)
+	}
+
+	// a plain text draft replaces the structured one, whatever it looks like
+	for _, legacy := range []string{"plain text", "{not json", `{"text":"json"}`} {
+		if err := c.SetConversationDraft(ctx, conversationID, legacy); err != nil {
+			t.Fatal(err)
+		}
+		got, err := c.GetConversationStructuredDraft(ctx, conversationID)
+		if err != nil {
+			t.Fatalf("legacy draft %q: %v", legacy, err)
+		}
+		if got.Text != legacy || len(got.Attachments) != 0 || got.QuoteClientMsgID != "" {
+			t.Errorf("legacy draft %q got %+v, want it as plain text", legacy, got)
+		}
+	}
+
+	// structured_draft that does not unmarshal falls back to draft_text
+	if err := c.db.UpdateColumnsConversation(ctx, conversationID, map[string]interface{}{"structured_draft": "{broken"}); err != nil {
+		t.Fatal(err)
+	}
+	if got, err := c.GetConversationStructuredDraft(ctx, conversationID); err != nil || got.Text != `{"text":"json"}` {
+		t.Errorf("broken structured draft got %+v, %v, want the plain text draft", got, err)
+	}
+
+	if err := c.SetConversationStructuredDraft(ctx, conversationID, draft); err != nil {
+		t.Fatal(err)
+	}
+	if err := c.SetConversationStructuredDraft(ctx, conversationID, &sdk_params_callback.StructuredDraft{}); err != nil {
+		t.Fatal(err)
+	}
+	got, err = c.GetConversationStructuredDraft(ctx, conversationID)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got.Text != "" || len(got.Attachments) != 0 {
+		t.Errorf("empty draft got %+v, want the draft removed", got)
+	}
+}
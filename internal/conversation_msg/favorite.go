@@ -0,0 +1,69 @@
+// Copyright © 2023 OpenIM SDK. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+package conversation_msg
+
+import (
+	"context"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/sdk_params_callback"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/utils"
+	"github.com/openimsdk/openim-sdk-core/v3/sdk_struct"
+	"github.com/openimsdk/tools/log"
+)
+
+// AddFavorite collects a message. A snapshot of the message is stored, so the favorite is kept
+// when the conversation is cleared or the message is purged. Collecting it again refreshes the snapshot.
+func (c *Conversation) AddFavorite(ctx context.Context, conversationID, clientMsgID string) error {
+	msg, err := c.db.GetMessage(ctx, conversationID, clientMsgID)
+	if err != nil {
+		return err
+	}
+	if err := c.db.DeleteFavorite(ctx, conversationID, clientMsgID); err != nil {
+		return err
+	}
+	return c.db.InsertFavorite(ctx, &model_struct.LocalFavorite{
+		ClientMsgID:    clientMsgID,
+		ConversationID: conversationID,
+		Message:        utils.StructToJsonString(LocalChatLogToMsgStruct(msg)),
+		CreateTime:     utils.GetCurrentTimestampByMill(),
+	})
+}
+
+func (c *Conversation) RemoveFavorite(ctx context.Context, conversationID, clientMsgID string) error {
+	return c.db.DeleteFavorite(ctx, conversationID, clientMsgID)
+}
+
+// GetFavoriteList returns the collected messages, the most recently added first.
+func (c *Conversation) GetFavoriteList(ctx context.Context, offset, count int) ([]*sdk_params_callback.FavoriteMessage, error) {
+	favorites, err := c.db.GetFavoriteList(ctx, offset, count)
+	if err != nil {
+		return nil, err
+	}
+	res := make([]*sdk_params_callback.FavoriteMessage, 0, len(favorites))
+	for _, favorite := range favorites {
+		msg := &sdk_struct.MsgStruct{}
+		if err := utils.JsonStringToStruct(favorite.Message, msg); err != nil {
+			log.ZWarn(ctx, "favorite message unmarshal err", err, "clientMsgID", favorite.ClientMsgID)
+			continue
+		}
+		res = append(res, &sdk_params_callback.FavoriteMessage{
+			ConversationID: favorite.ConversationID,
+			CreateTime:     favorite.CreateTime,
+			Message:        msg,
+		})
+	}
+	return res, nil
+}
@@ -0,0 +1,56 @@
+//go:build !js
+// +build !js
+
+package conversation_msg
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/constant"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+)
+
+func TestFavorites(t *testing.T) {
+	ctx := context.Background()
+	c, _, _ := newTestConversation(t)
+	now := time.Now().UnixMilli()
+	conversationIDs := []string{"si_peer_a_test_user", "si_peer_b_test_user"}
+	for _, conversationID := range conversationIDs {
+		insertTestMsgs(t, c, &model_struct.LocalConversation{ConversationID: conversationID, ConversationType: constant.SingleChatType},
+			newTestTextMsg("same_msg_id", testLoginUserID, 1, now, true))
+	}
+
+	for _, conversationID := range conversationIDs {
+		if err := c.AddFavorite(ctx, conversationID, "same_msg_id"); err != nil {
+			t.Fatal(err)
+		}
+	}
+	// collecting a message twice refreshes it instead of failing
+	time.Sleep(2 * time.Millisecond)
+	if err := c.AddFavorite(ctx, conversationIDs[0], "same_msg_id"); err != nil {
+		t.Fatalf("favorite the same message again: %v", err)
+	}
+	favorites, err := c.GetFavoriteList(ctx, 0, 10)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(favorites) != 2 {
+		t.Fatalf("got %d favorites, want one per conversation", len(favorites))
+	}
+	if favorites[0].ConversationID != conversationIDs[0] || favorites[0].Message.ClientMsgID != "same_msg_id" {
+		t.Errorf("latest favorite = %s/%s, want the one collected again first", favorites[0].ConversationID, favorites[0].Message.ClientMsgID)
+	}
+
+	if err := c.RemoveFavorite(ctx, conversationIDs[0], "same_msg_id"); err != nil {
+		t.Fatal(err)
+	}
+	favorites, err = c.GetFavoriteList(ctx, 0, 10)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(favorites) != 1 || favorites[0].ConversationID != conversationIDs[1] {
+		t.Fatalf("after removing one favorite got %+v, want only %s left", favorites, conversationIDs[1])
+	}
+}
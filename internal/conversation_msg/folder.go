@@ -0,0 +1,86 @@
+// Copyright © 2023 OpenIM SDK. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+package conversation_msg
+
+import (
+	"context"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/sdkerrs"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/utils"
+	"github.com/openimsdk/tools/utils/datautil"
+)
+
+// Conversation folders only exist on this device, they are not synced to the server.
+
+func (c *Conversation) CreateConversationFolder(ctx context.Context, name string) (*model_struct.LocalConversationFolder, error) {
+	if name == "" {
+		return nil, sdkerrs.ErrArgs.WrapMsg("folder name is empty")
+	}
+	folder := &model_struct.LocalConversationFolder{
+		FolderID:   utils.GetMsgID(c.loginUserID),
+		Name:       name,
+		CreateTime: utils.GetCurrentTimestampByMill(),
+	}
+	if err := c.db.InsertConversationFolder(ctx, folder); err != nil {
+		return nil, err
+	}
+	return folder, nil
+}
+
+func (c *Conversation) RenameConversationFolder(ctx context.Context, folderID, name string) error {
+	if name == "" {
+		return sdkerrs.ErrArgs.WrapMsg("folder name is empty")
+	}
+	folder, err := c.db.GetConversationFolder(ctx, folderID)
+	if err != nil {
+		return err
+	}
+	folder.Name = name
+	return c.db.UpdateConversationFolder(ctx, folder)
+}
+
+func (c *Conversation) DeleteConversationFolder(ctx context.Context, folderID string) error {
+	return c.db.DeleteConversationFolder(ctx, folderID)
+}
+
+func (c *Conversation) GetConversationFolders(ctx context.Context) ([]*model_struct.LocalConversationFolder, error) {
+	return c.db.GetAllConversationFolders(ctx)
+}
+
+func (c *Conversation) AddConversationsToFolder(ctx context.Context, folderID string, conversationIDs []string) error {
+	if _, err := c.db.GetConversationFolder(ctx, folderID); err != nil {
+		return err
+	}
+	conversationIDs = datautil.Distinct(conversationIDs)
+	// remove first so conversations already in the folder do not conflict
+	if err := c.db.DeleteFolderConversations(ctx, folderID, conversationIDs); err != nil {
+		return err
+	}
+	folderConversations := make([]*model_struct.LocalFolderConversation, 0, len(conversationIDs))
+	for _, conversationID := range conversationIDs {
+		folderConversations = append(folderConversations, &model_struct.LocalFolderConversation{FolderID: folderID, ConversationID: conversationID})
+	}
+	return c.db.BatchInsertFolderConversations(ctx, folderConversations)
+}
+
+func (c *Conversation) RemoveConversationsFromFolder(ctx context.Context, folderID string, conversationIDs []string) error {
+	return c.db.DeleteFolderConversations(ctx, folderID, conversationIDs)
+}
+
+// GetConversationListByFolder pages the conversations of a folder, sorted like GetConversationListSplit.
+func (c *Conversation) GetConversationListByFolder(ctx context.Context, folderID string, offset, count int) ([]*model_struct.LocalConversation, error) {
+	return c.db.GetConversationListByFolderDB(ctx, folderID, offset, count)
+}
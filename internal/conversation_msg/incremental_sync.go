@@ -23,6 +23,8 @@ import (
 	"github.com/openimsdk/tools/utils/datautil"
 )
 
+const conversationSyncBatchSize = 500
+
 func (c *Conversation) IncrSyncConversations(ctx context.Context) error {
 	conversationSyncer := syncer.VersionSynchronizer[*model_struct.LocalConversation, *pbConversation.GetIncrementalConversationResp]{
 		Ctx:       ctx,
@@ -35,6 +37,9 @@ func (c *Conversation) IncrSyncConversations(ctx context.Context) error {
 		Local: func() ([]*model_struct.LocalConversation, error) {
 			return c.db.GetAllConversations(ctx)
 		},
+		LocalByIDs: func(conversationIDs []string) ([]*model_struct.LocalConversation, error) {
+			return c.db.GetMultipleConversationDB(ctx, conversationIDs)
+		},
 		Server: func(version *model_struct.LocalVersionSync) (*pbConversation.GetIncrementalConversationResp, error) {
 			return c.getIncrementalConversationFromServer(ctx, version.Version, version.VersionID)
 		},
@@ -64,16 +69,11 @@ func (c *Conversation) IncrSyncConversations(ctx context.Context) error {
 			if len(conversationIDList) == 0 {
 				return c.conversationSyncer.FullSync(ctx, c.loginUserID)
 			} else {
-				local, err := c.db.GetAllConversations(ctx)
-				if err != nil {
-					return err
-				}
 				resp, err := c.getAllConversationListFromServer(ctx)
 				if err != nil {
 					return err
 				}
-				server := datautil.Batch(ServerConversationToLocal, resp.Conversations)
-				return c.conversationSyncer.Sync(ctx, server, local, nil, true)
+				return c.syncConversationsInBatches(ctx, datautil.Batch(ServerConversationToLocal, resp.Conversations))
 			}
 		},
 		FullID: func(ctx context.Context) ([]string, error) {
@@ -88,6 +88,25 @@ func (c *Conversation) IncrSyncConversations(ctx context.Context) error {
 	return conversationSyncer.IncrementalSync()
 }
 
+// syncConversationsInBatches diffs the server conversations against the local rows with the same IDs,
+// one batch at a time, so that a full sync never loads the whole local table.
+// Local conversations missing on the server are kept, like in the incremental sync.
+func (c *Conversation) syncConversationsInBatches(ctx context.Context, server []*model_struct.LocalConversation) error {
+	for start := 0; start < len(server); start += conversationSyncBatchSize {
+		batch := server[start:min(start+conversationSyncBatchSize, len(server))]
+		local, err := c.db.GetMultipleConversationDB(ctx, datautil.Slice(batch, func(conversation *model_struct.LocalConversation) string {
+			return conversation.ConversationID
+		}))
+		if err != nil {
+			return err
+		}
+		if err := c.conversationSyncer.Sync(ctx, batch, local, nil, true); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
 func (c *Conversation) IncrSyncConversationsWithLock(ctx context.Context) error {
 	c.conversationSyncMutex.Lock()
 	defer c.conversationSyncMutex.Unlock()
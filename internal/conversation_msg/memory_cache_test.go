@@ -0,0 +1,51 @@
+//go:build !js
+// +build !js
+
+package conversation_msg
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/cache"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/utils"
+)
+
+func TestClearMemoryCaches(t *testing.T) {
+	c, _, _ := newTestConversation(t)
+	c.DataDir = t.TempDir() + "/"
+	c.cache = cache.NewCache[string, *model_struct.LocalConversation]()
+	c.pendingThumbnails = cache.NewCache[string, string]()
+
+	c.cache.Store("si_peer_a_test_user", &model_struct.LocalConversation{ConversationID: "si_peer_a_test_user"})
+	// the source of m1 still exists, the copy can be read again from it
+	source := filepath.Join(t.TempDir(), "snapshot.jpg")
+	if err := os.WriteFile(source, []byte("jpg"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	// the source of m2 is gone, its copy is the only one left for a resend
+	missing := filepath.Join(t.TempDir(), "missing.jpg")
+	for clientMsgID, snapshotPath := range map[string]string{"m1": source, "m2": missing} {
+		if err := os.WriteFile(utils.FileTmpPath(snapshotPath, c.DataDir), []byte("jpg"), 0644); err != nil {
+			t.Fatal(err)
+		}
+		c.pendingThumbnails.Store(clientMsgID, snapshotPath)
+	}
+
+	c.ClearMemoryCaches(context.Background())
+	if values := c.cache.RangeAll(); len(values) != 0 {
+		t.Errorf("%d conversations left in the cache", len(values))
+	}
+	if values := c.pendingThumbnails.RangeAll(); len(values) != 0 {
+		t.Errorf("%d pending thumbnails left", len(values))
+	}
+	if utils.FileExist(utils.FileTmpPath(source, c.DataDir)) {
+		t.Error("the thumbnail copy of an existing source was kept")
+	}
+	if !utils.FileExist(utils.FileTmpPath(missing, c.DataDir)) {
+		t.Error("the only thumbnail copy of a missing source was removed")
+	}
+}
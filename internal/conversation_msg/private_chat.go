@@ -0,0 +1,108 @@
+// Copyright © 2023 OpenIM SDK. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+package conversation_msg
+
+import (
+	"context"
+	"time"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/utils"
+	"github.com/openimsdk/openim-sdk-core/v3/sdk_struct"
+	"github.com/openimsdk/tools/log"
+)
+
+// isBurned reports whether a read private chat message has outlived its burn duration.
+func isBurned(attachedInfo *sdk_struct.AttachedInfoElem, now int64) bool {
+	if attachedInfo == nil || !attachedInfo.IsPrivateChat || attachedInfo.HasReadTime == 0 {
+		return false
+	}
+	return attachedInfo.HasReadTime+int64(attachedInfo.BurnDuration)*1000 <= now
+}
+
+// scheduleBurn runs the destruct loop once the burn duration has passed.
+// Nothing is scheduled when the loop is not running, its first run after login burns the messages.
+func (c *Conversation) scheduleBurn(burnDuration int32) {
+	m := c.msgDestruct
+	m.lock.Lock()
+	defer m.lock.Unlock()
+	if !m.running {
+		return
+	}
+	var timer *time.Timer
+	timer = time.AfterFunc(time.Duration(burnDuration)*time.Second, func() {
+		m.lock.Lock()
+		delete(m.burnTimers, timer)
+		m.lock.Unlock()
+		c.triggerMsgDestruct()
+	})
+	m.burnTimers[timer] = struct{}{}
+}
+
+// startBurnTimer records the read time of messages of a private chat, which starts their burn timer.
+func (c *Conversation) startBurnTimer(ctx context.Context, conversation *model_struct.LocalConversation, msgs []*model_struct.LocalChatLog, readTime int64) {
+	if !conversation.IsPrivateChat {
+		return
+	}
+	var burnDuration int32
+	for _, msg := range msgs {
+		if msg.SendID == c.loginUserID {
+			continue
+		}
+		attachedInfo := sdk_struct.AttachedInfoElem{}
+		_ = utils.JsonStringToStruct(msg.AttachedInfo, &attachedInfo)
+		if attachedInfo.HasReadTime != 0 {
+			continue
+		}
+		attachedInfo.IsPrivateChat = true
+		if attachedInfo.BurnDuration == 0 {
+			attachedInfo.BurnDuration = conversation.BurnDuration
+		}
+		attachedInfo.HasReadTime = readTime
+		msg.AttachedInfo = utils.StructToJsonString(attachedInfo)
+		if err := c.db.UpdateColumnsMessage(ctx, conversation.ConversationID, msg.ClientMsgID, map[string]interface{}{"attached_info": msg.AttachedInfo}); err != nil {
+			log.ZWarn(ctx, "UpdateColumnsMessage err", err, "conversationID", conversation.ConversationID, "clientMsgID", msg.ClientMsgID)
+			continue
+		}
+		if attachedInfo.BurnDuration > burnDuration {
+			burnDuration = attachedInfo.BurnDuration
+		}
+	}
+	if burnDuration > 0 {
+		c.scheduleBurn(burnDuration)
+	}
+}
+
+// burnPrivateChatMsgs deletes the read messages of a private chat whose burn duration has passed.
+func (c *Conversation) burnPrivateChatMsgs(ctx context.Context, conversation *model_struct.LocalConversation) error {
+	c.conversationSyncMutex.Lock()
+	defer c.conversationSyncMutex.Unlock()
+	burned, err := c.db.GetBurnedPrivateChatMessages(ctx, conversation.ConversationID, time.Now().UnixMilli())
+	if err != nil {
+		return err
+	}
+	if len(burned) == 0 {
+		return nil
+	}
+	clientMsgIDs := make([]string, 0, len(burned))
+	for _, msg := range burned {
+		clientMsgIDs = append(clientMsgIDs, msg.ClientMsgID)
+	}
+	if err := c.db.DeleteConversationMsgs(ctx, conversation.ConversationID, clientMsgIDs); err != nil {
+		return err
+	}
+	log.ZInfo(ctx, "burn private chat msgs", "conversationID", conversation.ConversationID, "count", len(burned))
+	return c.msgsPurged(ctx, conversation, burned)
+}
@@ -0,0 +1,118 @@
+//go:build !js
+// +build !js
+
+package conversation_msg
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/constant"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/utils"
+	"github.com/openimsdk/openim-sdk-core/v3/sdk_struct"
+)
+
+func TestIsBurned(t *testing.T) {
+	now := time.Now().UnixMilli()
+	tests := []struct {
+		name         string
+		attachedInfo *sdk_struct.AttachedInfoElem
+		want         bool
+	}{
+		{"nil", nil, false},
+		{"not private chat", &sdk_struct.AttachedInfoElem{BurnDuration: 10, HasReadTime: now - 20000}, false},
+		{"not read", &sdk_struct.AttachedInfoElem{IsPrivateChat: true, BurnDuration: 10}, false},
+		{"burning", &sdk_struct.AttachedInfoElem{IsPrivateChat: true, BurnDuration: 30, HasReadTime: now - 20000}, false},
+		{"burn duration just passed", &sdk_struct.AttachedInfoElem{IsPrivateChat: true, BurnDuration: 20, HasReadTime: now - 20000}, true},
+		{"burned", &sdk_struct.AttachedInfoElem{IsPrivateChat: true, BurnDuration: 10, HasReadTime: now - 20000}, true},
+	}
+	for _, tt := range tests {
+		if got := isBurned(tt.attachedInfo, now); got != tt.want {
+			t.Errorf("%s: isBurned = %v, want %v", tt.name, got, tt.want)
+		}
+	}
+}
+
+func newTestPrivateChatMsg(clientMsgID string, seq, sendTime int64, burnDuration int32, hasReadTime int64) *model_struct.LocalChatLog {
+	msg := newTestTextMsg(clientMsgID, "peer_a", seq, sendTime, hasReadTime != 0)
+	msg.AttachedInfo = utils.StructToJsonString(sdk_struct.AttachedInfoElem{IsPrivateChat: true, BurnDuration: burnDuration, HasReadTime: hasReadTime})
+	return msg
+}
+
+func TestBurnPrivateChatMsgs(t *testing.T) {
+	ctx := context.Background()
+	c, _, msgListener := newTestConversation(t)
+	now := time.Now().UnixMilli()
+
+	conversation := &model_struct.LocalConversation{
+		ConversationID:   "si_peer_a_test_user",
+		ConversationType: constant.SingleChatType,
+		UserID:           "peer_a",
+		UnreadCount:      1,
+		IsPrivateChat:    true,
+		BurnDuration:     10,
+	}
+	noAttachedInfo := newTestTextMsg("no_attached_info", "peer_a", 1, now-50000, true)
+	insertTestMsgs(t, c, conversation,
+		noAttachedInfo,
+		newTestPrivateChatMsg("burning", 2, now-40000, 30, now-10000),
+		newTestPrivateChatMsg("unread", 3, now-30000, 10, 0),
+		newTestPrivateChatMsg("burned", 4, now-20000, 10, now-15000),
+	)
+
+	if err := c.burnPrivateChatMsgs(ctx, conversation); err != nil {
+		t.Fatal(err)
+	}
+
+	msgs, err := c.db.GetMessageList(ctx, conversation.ConversationID, 10, 0, 0, "", false)
+	if err != nil {
+		t.Fatal(err)
+	}
+	kept := make(map[string]bool)
+	for _, msg := range msgs {
+		kept[msg.ClientMsgID] = true
+	}
+	if len(msgs) != 3 || !kept["no_attached_info"] || !kept["burning"] || !kept["unread"] {
+		t.Fatalf("kept %v, want every message but the burned one", kept)
+	}
+	if len(msgListener.deleted) != 1 {
+		t.Errorf("OnMsgDeleted called %d times, want 1", len(msgListener.deleted))
+	}
+	repaired, latestMsg := getTestConversation(t, c, conversation.ConversationID)
+	if latestMsg.ClientMsgID != "unread" {
+		t.Errorf("latest msg = %q, want unread", latestMsg.ClientMsgID)
+	}
+	if repaired.UnreadCount != 1 {
+		t.Errorf("unread count = %d, want 1", repaired.UnreadCount)
+	}
+}
+
+func TestScheduleBurnStopped(t *testing.T) {
+	c, _, _ := newTestConversation(t)
+
+	c.scheduleBurn(60)
+	if len(c.msgDestruct.burnTimers) != 0 {
+		t.Fatal("a burn timer was scheduled while the destruct loop is not running")
+	}
+
+	c.msgDestruct.start()
+	c.scheduleBurn(60)
+	if len(c.msgDestruct.burnTimers) != 1 {
+		t.Fatalf("got %d burn timers, want 1", len(c.msgDestruct.burnTimers))
+	}
+	timer := func() *time.Timer {
+		for timer := range c.msgDestruct.burnTimers {
+			return timer
+		}
+		return nil
+	}()
+	c.msgDestruct.stop()
+	if len(c.msgDestruct.burnTimers) != 0 {
+		t.Fatal("burn timers are left after the destruct loop stopped")
+	}
+	if timer.Stop() {
+		t.Error("the burn timer is still pending after the destruct loop stopped")
+	}
+}
@@ -18,6 +18,7 @@ import (
 	"context"
 	"encoding/json"
 	"errors"
+	"time"
 
 	"github.com/openimsdk/openim-sdk-core/v3/pkg/common"
 	"github.com/openimsdk/openim-sdk-core/v3/pkg/constant"
@@ -86,6 +87,8 @@ func (c *Conversation) markConversationMessageAsRead(ctx context.Context, conver
 			_, err = c.db.MarkConversationMessageAsReadDB(ctx, conversationID, msgIDs)
 			if err != nil {
 				log.ZWarn(ctx, "MarkConversationMessageAsRead err", err, "conversationID", conversationID, "msgIDs", msgIDs)
+			} else {
+				c.startBurnTimer(ctx, conversation, msgs, time.Now().UnixMilli())
 			}
 		}
 	case constant.ReadGroupChatType, constant.NotificationChatType:
@@ -105,7 +108,7 @@ func (c *Conversation) markConversationMessageAsRead(ctx context.Context, conver
 
 // mark a conversation's message as read by seqs
 func (c *Conversation) markMessagesAsReadByMsgID(ctx context.Context, conversationID string, msgIDs []string) error {
-	_, err := c.db.GetConversation(ctx, conversationID)
+	conversation, err := c.db.GetConversation(ctx, conversationID)
 	if err != nil {
 		return err
 	}
@@ -138,6 +141,7 @@ func (c *Conversation) markMessagesAsReadByMsgID(ctx context.Context, conversati
 		log.ZError(ctx, "decrConversationUnreadCount err", err, "conversationID", conversationID,
 			"decrCount", decrCount)
 	}
+	c.startBurnTimer(ctx, conversation, msgs, time.Now().UnixMilli())
 	c.unreadChangeTrigger(ctx, conversationID, hasReadSeq == maxSeq && msgs[0].SendID != c.loginUserID)
 	return nil
 }
@@ -170,6 +174,37 @@ func (c *Conversation) unreadChangeTrigger(ctx context.Context, conversationID s
 		Ctx: ctx})
 }
 
+// recalculate a conversation's unread count from its max seq and has read seq,
+// repairing the local row when it has drifted.
+func (c *Conversation) recalculateUnreadCount(ctx context.Context, conversationID string) error {
+	c.conversationSyncMutex.Lock()
+	defer c.conversationSyncMutex.Unlock()
+	conversation, err := c.db.GetConversation(ctx, conversationID)
+	if err != nil {
+		return err
+	}
+	resp, err := c.getHasReadAndMaxSeqsFromServer(ctx, conversationID)
+	if err != nil {
+		return err
+	}
+	seqs, ok := resp.Seqs[conversationID]
+	if !ok {
+		log.ZWarn(ctx, "conversation seqs not found", nil, "conversationID", conversationID)
+		return nil
+	}
+	c.maxSeqRecorder.Set(conversationID, seqs.MaxSeq)
+	unreadCount := unreadCountFromSeqs(ctx, conversationID, seqs)
+	if conversation.UnreadCount != unreadCount {
+		log.ZInfo(ctx, "repair unread count", "conversationID", conversationID, "local", conversation.UnreadCount, "recalculated", unreadCount)
+		if err := c.db.UpdateColumnsConversation(ctx, conversationID, map[string]interface{}{"unread_count": unreadCount}); err != nil {
+			return err
+		}
+		c.doUpdateConversation(common.Cmd2Value{Value: common.UpdateConNode{ConID: conversationID, Action: constant.ConChange, Args: []string{conversationID}}, Ctx: ctx})
+	}
+	c.doUpdateConversation(common.Cmd2Value{Value: common.UpdateConNode{Action: constant.TotalUnreadMessageChanged}, Ctx: ctx})
+	return nil
+}
+
 func (c *Conversation) doUnreadCount(ctx context.Context, conversation *model_struct.LocalConversation, hasReadSeq int64, seqs []int64) error {
 	if conversation.ConversationType == constant.SingleChatType {
 		if len(seqs) != 0 {
@@ -185,6 +220,14 @@ func (c *Conversation) doUnreadCount(ctx context.Context, conversation *model_st
 				if err != nil {
 					return err
 				}
+				if conversation.IsPrivateChat {
+					msgs, err := c.db.GetMessagesBySeqs(ctx, conversation.ConversationID, seqs)
+					if err != nil {
+						log.ZWarn(ctx, "GetMessagesBySeqs err", err, "conversationID", conversation.ConversationID, "seqs", seqs)
+					} else {
+						c.startBurnTimer(ctx, conversation, msgs, time.Now().UnixMilli())
+					}
+				}
 			}
 
 		} else {
@@ -274,6 +317,9 @@ func (c *Conversation) doReadDrawing(ctx context.Context, msg *sdkws.MsgData) er
 					successMsgIDs = append(successMsgIDs, message.ClientMsgID)
 				}
 			}
+			if conversation.IsPrivateChat && len(successMsgIDs) > 0 {
+				c.scheduleBurn(conversation.BurnDuration)
+			}
 			var messageReceiptResp = []*sdk_struct.MessageReceipt{{UserID: tips.MarkAsReadUserID, MsgIDList: successMsgIDs,
 				SessionType: conversation.ConversationType, ReadTime: msg.SendTime}}
 			c.msgListener().OnRecvC2CReadReceipt(utils.StructToJsonString(messageReceiptResp))
@@ -0,0 +1,72 @@
+// Copyright © 2023 OpenIM SDK. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+package conversation_msg
+
+import (
+	"context"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/constant"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/utils"
+	"github.com/openimsdk/openim-sdk-core/v3/sdk_struct"
+)
+
+func (c *Conversation) StarMessage(ctx context.Context, conversationID, clientMsgID string, isStarred bool) error {
+	if !isStarred {
+		return c.db.DeleteStarredMessage(ctx, conversationID, clientMsgID)
+	}
+	if _, err := c.db.GetMessage(ctx, conversationID, clientMsgID); err != nil {
+		return err
+	}
+	if err := c.db.DeleteStarredMessage(ctx, conversationID, clientMsgID); err != nil {
+		return err
+	}
+	return c.db.InsertStarredMessage(ctx, &model_struct.LocalStarredMessage{
+		ConversationID: conversationID,
+		ClientMsgID:    clientMsgID,
+		CreateTime:     utils.GetCurrentTimestampByMill(),
+	})
+}
+
+// GetStarredMessages returns the starred messages of a conversation, the most recently starred first.
+// Messages deleted since they were starred are left out, their star is normally removed with them.
+func (c *Conversation) GetStarredMessages(ctx context.Context, conversationID string) ([]*sdk_struct.MsgStruct, error) {
+	starred, err := c.db.GetStarredMessageList(ctx, conversationID)
+	if err != nil {
+		return nil, err
+	}
+	if len(starred) == 0 {
+		return []*sdk_struct.MsgStruct{}, nil
+	}
+	clientMsgIDs := make([]string, 0, len(starred))
+	for _, s := range starred {
+		clientMsgIDs = append(clientMsgIDs, s.ClientMsgID)
+	}
+	msgs, err := c.db.GetMessagesByClientMsgIDs(ctx, conversationID, clientMsgIDs)
+	if err != nil {
+		return nil, err
+	}
+	msgMap := make(map[string]*model_struct.LocalChatLog, len(msgs))
+	for _, msg := range msgs {
+		msgMap[msg.ClientMsgID] = msg
+	}
+	res := make([]*sdk_struct.MsgStruct, 0, len(msgs))
+	for _, s := range starred {
+		if msg, ok := msgMap[s.ClientMsgID]; ok && msg.Status != constant.MsgStatusHasDeleted {
+			res = append(res, LocalChatLogToMsgStruct(msg))
+		}
+	}
+	return res, nil
+}
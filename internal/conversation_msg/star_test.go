@@ -0,0 +1,141 @@
+//go:build !js
+// +build !js
+
+package conversation_msg
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/constant"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/sdk_struct"
+)
+
+func starredMsgIDs(msgs []*sdk_struct.MsgStruct) []string {
+	ids := make([]string, 0, len(msgs))
+	for _, msg := range msgs {
+		ids = append(ids, msg.ClientMsgID)
+	}
+	return ids
+}
+
+func TestStarMessage(t *testing.T) {
+	ctx := context.Background()
+	c, _, _ := newTestConversation(t)
+	now := time.Now().UnixMilli()
+	conversationID := "si_peer_a_test_user"
+	insertTestMsgs(t, c, &model_struct.LocalConversation{ConversationID: conversationID, ConversationType: constant.SingleChatType},
+		newTestTextMsg("m1", "peer_a", 1, now-3000, true),
+		newTestTextMsg("m2", testLoginUserID, 2, now-2000, true),
+		newTestTextMsg("m3", "peer_a", 3, now-1000, true),
+	)
+
+	if err := c.StarMessage(ctx, conversationID, "not_exist", true); err == nil {
+		t.Fatal("starring a message that does not exist should fail")
+	}
+	for _, clientMsgID := range []string{"m1", "m2", "m3"} {
+		if err := c.StarMessage(ctx, conversationID, clientMsgID, true); err != nil {
+			t.Fatal(err)
+		}
+		time.Sleep(2 * time.Millisecond)
+	}
+	// starring again moves the message to the front instead of failing
+	if err := c.StarMessage(ctx, conversationID, "m1", true); err != nil {
+		t.Fatalf("star the same message again: %v", err)
+	}
+	msgs, err := c.GetStarredMessages(ctx, conversationID)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if ids := starredMsgIDs(msgs); len(ids) != 3 || ids[0] != "m1" || ids[1] != "m3" || ids[2] != "m2" {
+		t.Fatalf("starred = %v, want [m1 m3 m2]", ids)
+	}
+
+	if err := c.StarMessage(ctx, conversationID, "m3", false); err != nil {
+		t.Fatal(err)
+	}
+	// unstarring a message that is not starred is a no-op
+	if err := c.StarMessage(ctx, conversationID, "m3", false); err != nil {
+		t.Fatalf("unstar twice: %v", err)
+	}
+	if err := c.db.DeleteConversationMsgs(ctx, conversationID, []string{"m2"}); err != nil {
+		t.Fatal(err)
+	}
+	msgs, err = c.GetStarredMessages(ctx, conversationID)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if ids := starredMsgIDs(msgs); len(ids) != 1 || ids[0] != "m1" {
+		t.Fatalf("after unstarring m3 and deleting m2 starred = %v, want [m1]", ids)
+	}
+
+	msgs, err = c.GetStarredMessages(ctx, "si_peer_b_test_user")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if msgs == nil || len(msgs) != 0 {
+		t.Fatalf("starred of another conversation = %v, want an empty list", msgs)
+	}
+}
+
+func TestStarredMessagesRemovedWithMessages(t *testing.T) {
+	ctx := context.Background()
+	c, _, _ := newTestConversation(t)
+	now := time.Now().UnixMilli()
+
+	deleted := &model_struct.LocalConversation{ConversationID: "si_peer_a_test_user", ConversationType: constant.SingleChatType, UserID: "peer_a"}
+	insertTestMsgs(t, c, deleted,
+		newTestTextMsg("a1", "peer_a", 1, now-2000, true),
+		newTestTextMsg("a2", "peer_a", 2, now-1000, true),
+	)
+	// seq 0 so that clearing does not mark the conversation as read on the server
+	cleared := &model_struct.LocalConversation{ConversationID: "si_peer_b_test_user", ConversationType: constant.SingleChatType, UserID: "peer_b"}
+	insertTestMsgs(t, c, cleared, newTestTextMsg("b1", "peer_b", 0, now-1000, true))
+	destructed := &model_struct.LocalConversation{ConversationID: "si_peer_c_test_user", ConversationType: constant.SingleChatType, UserID: "peer_c",
+		IsMsgDestruct: true, MsgDestructTime: 60}
+	insertTestMsgs(t, c, destructed,
+		newTestTextMsg("c1", "peer_c", 1, now-time.Hour.Milliseconds(), true),
+		newTestTextMsg("c2", "peer_c", 2, now-1000, true),
+	)
+	for conversationID, clientMsgIDs := range map[string][]string{
+		deleted.ConversationID:    {"a1", "a2"},
+		cleared.ConversationID:    {"b1"},
+		destructed.ConversationID: {"c1", "c2"},
+	} {
+		for _, clientMsgID := range clientMsgIDs {
+			if err := c.StarMessage(ctx, conversationID, clientMsgID, true); err != nil {
+				t.Fatal(err)
+			}
+		}
+	}
+
+	if err := c.deleteMessageFromLocal(ctx, deleted.ConversationID, "a1"); err != nil {
+		t.Fatal(err)
+	}
+	if err := c.clearConversationAndDeleteAllMsg(ctx, cleared.ConversationID, false, c.db.ClearConversation); err != nil {
+		t.Fatal(err)
+	}
+	if err := c.destructConversationMsgs(ctx, destructed); err != nil {
+		t.Fatal(err)
+	}
+
+	for conversationID, want := range map[string][]string{
+		deleted.ConversationID:    {"a2"},
+		cleared.ConversationID:    {},
+		destructed.ConversationID: {"c2"},
+	} {
+		starred, err := c.db.GetStarredMessageList(ctx, conversationID)
+		if err != nil {
+			t.Fatal(err)
+		}
+		ids := make([]string, 0, len(starred))
+		for _, s := range starred {
+			ids = append(ids, s.ClientMsgID)
+		}
+		if len(ids) != len(want) || (len(want) == 1 && ids[0] != want[0]) {
+			t.Errorf("%s: starred rows = %v, want %v", conversationID, ids, want)
+		}
+	}
+}
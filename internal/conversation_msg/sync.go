@@ -27,7 +27,33 @@ import (
 	"github.com/openimsdk/tools/log"
 )
 
+// unreadCountFromSeqs is the unread count of a conversation given its max seq and has read seq, never negative.
+func unreadCountFromSeqs(ctx context.Context, conversationID string, seqs *msg.Seqs) int32 {
+	if seqs.MaxSeq-seqs.HasReadSeq < 0 {
+		log.ZWarn(ctx, "unread count is less than 0", nil, "conversationID", conversationID,
+			"maxSeq", seqs.MaxSeq, "hasReadSeq", seqs.HasReadSeq)
+		return 0
+	}
+	return int32(seqs.MaxSeq - seqs.HasReadSeq)
+}
+
 func (c *Conversation) SyncAllConversationHashReadSeqs(ctx context.Context) error {
+	conversationChangedIDs, err := c.syncAllConversationHasReadSeqs(ctx)
+	if err != nil {
+		return err
+	}
+	if len(conversationChangedIDs) > 0 {
+		stepStartTime := time.Now()
+		common.DispatchUpdateConversation(ctx, common.UpdateConNode{Action: constant.ConChange, Args: conversationChangedIDs}, c.ConversationEventQueue())
+		common.DispatchUpdateConversation(ctx, common.UpdateConNode{Action: constant.TotalUnreadMessageChanged}, c.ConversationEventQueue())
+		log.ZDebug(ctx, "TriggerCmdUpdateConversation completed", "duration", time.Since(stepStartTime).Seconds())
+	}
+	return nil
+}
+
+// syncAllConversationHasReadSeqs updates the unread counts of all conversations from the server seqs
+// and returns the IDs of the local conversations whose count changed, without notifying listeners.
+func (c *Conversation) syncAllConversationHasReadSeqs(ctx context.Context) ([]string, error) {
 	startTime := time.Now()
 	log.ZDebug(ctx, "start SyncConversationHashReadSeqs")
 
@@ -36,13 +62,13 @@ func (c *Conversation) SyncAllConversationHashReadSeqs(ctx context.Context) erro
 	err := c.SendReqWaitResp(ctx, &req, constant.GetConvMaxReadSeq, &resp)
 	if err != nil {
 		log.ZWarn(ctx, "SendReqWaitResp err", err)
-		return err
+		return nil, err
 	}
 	seqs := resp.Seqs
 	log.ZDebug(ctx, "getServerHasReadAndMaxSeqs completed", "duration", time.Since(startTime).Seconds())
 
 	if len(seqs) == 0 {
-		return nil
+		return nil, nil
 	}
 	var conversationChangedIDs []string
 	var conversationIDsNeedSync []string
@@ -51,7 +77,7 @@ func (c *Conversation) SyncAllConversationHashReadSeqs(ctx context.Context) erro
 	conversationsOnLocal, err := c.db.GetAllConversations(ctx)
 	if err != nil {
 		log.ZWarn(ctx, "get all conversations err", err)
-		return err
+		return nil, err
 	}
 	log.ZDebug(ctx, "GetAllConversations completed", "duration", time.Since(stepStartTime).Seconds())
 
@@ -61,15 +87,8 @@ func (c *Conversation) SyncAllConversationHashReadSeqs(ctx context.Context) erro
 
 	stepStartTime = time.Now()
 	for conversationID, v := range seqs {
-		var unreadCount int32
 		c.maxSeqRecorder.Set(conversationID, v.MaxSeq)
-		if v.MaxSeq-v.HasReadSeq < 0 {
-			unreadCount = 0
-			log.ZWarn(ctx, "unread count is less than 0", nil, "conversationID",
-				conversationID, "maxSeq", v.MaxSeq, "hasReadSeq", v.HasReadSeq)
-		} else {
-			unreadCount = int32(v.MaxSeq - v.HasReadSeq)
-		}
+		unreadCount := unreadCountFromSeqs(ctx, conversationID, v)
 		if conversation, ok := conversationsOnLocalMap[conversationID]; ok {
 			if conversation.UnreadCount != unreadCount {
 				if err := c.db.UpdateColumnsConversation(ctx, conversationID, map[string]interface{}{"unread_count": unreadCount}); err != nil {
@@ -89,30 +108,23 @@ func (c *Conversation) SyncAllConversationHashReadSeqs(ctx context.Context) erro
 		r, err := c.getConversationsByIDsFromServer(ctx, conversationIDsNeedSync)
 		if err != nil {
 			log.ZWarn(ctx, "getServerConversationsByIDs err", err, "conversationIDs", conversationIDsNeedSync)
-			return err
+			return nil, err
 		}
 		log.ZDebug(ctx, "getServerConversationsByIDs completed", "duration", time.Since(stepStartTime).Seconds())
 		conversationsOnServer := datautil.Batch(ServerConversationToLocal, r.Conversations)
 		stepStartTime = time.Now()
 		if err := c.batchAddFaceURLAndName(ctx, conversationsOnServer...); err != nil {
 			log.ZWarn(ctx, "batchAddFaceURLAndName err", err, "conversationsOnServer", conversationsOnServer)
-			return err
+			return nil, err
 		}
 		log.ZDebug(ctx, "batchAddFaceURLAndName completed", "duration", time.Since(stepStartTime).Seconds())
 
 		for _, conversation := range conversationsOnServer {
-			var unreadCount int32
 			v, ok := seqs[conversation.ConversationID]
 			if !ok {
 				continue
 			}
-			if v.MaxSeq-v.HasReadSeq < 0 {
-				unreadCount = 0
-				log.ZWarn(ctx, "unread count is less than 0", nil, "server seq", v, "conversation", conversation)
-			} else {
-				unreadCount = int32(v.MaxSeq - v.HasReadSeq)
-			}
-			conversation.UnreadCount = unreadCount
+			conversation.UnreadCount = unreadCountFromSeqs(ctx, conversation.ConversationID, v)
 		}
 
 		stepStartTime = time.Now()
@@ -124,13 +136,6 @@ func (c *Conversation) SyncAllConversationHashReadSeqs(ctx context.Context) erro
 	}
 
 	log.ZDebug(ctx, "update conversations", "conversations", conversationChangedIDs)
-	if len(conversationChangedIDs) > 0 {
-		stepStartTime = time.Now()
-		common.DispatchUpdateConversation(ctx, common.UpdateConNode{Action: constant.ConChange, Args: conversationChangedIDs}, c.ConversationEventQueue())
-		common.DispatchUpdateConversation(ctx, common.UpdateConNode{Action: constant.TotalUnreadMessageChanged}, c.ConversationEventQueue())
-		log.ZDebug(ctx, "TriggerCmdUpdateConversation completed", "duration", time.Since(stepStartTime).Seconds())
-	}
-
 	log.ZDebug(ctx, "SyncAllConversationHashReadSeqs completed", "totalDuration", time.Since(startTime).Seconds())
-	return nil
+	return conversationChangedIDs, nil
 }
@@ -0,0 +1,30 @@
+//go:build !js
+// +build !js
+
+package conversation_msg
+
+import (
+	"context"
+	"testing"
+
+	"github.com/openimsdk/protocol/msg"
+)
+
+func TestUnreadCountFromSeqs(t *testing.T) {
+	tests := []struct {
+		name string
+		seqs *msg.Seqs
+		want int32
+	}{
+		{"unread", &msg.Seqs{MaxSeq: 10, HasReadSeq: 4}, 6},
+		{"all read", &msg.Seqs{MaxSeq: 10, HasReadSeq: 10}, 0},
+		{"zero seqs", &msg.Seqs{}, 0},
+		{"nothing read", &msg.Seqs{MaxSeq: 3}, 3},
+		{"has read seq ahead of max seq", &msg.Seqs{MaxSeq: 4, HasReadSeq: 10}, 0},
+	}
+	for _, tt := range tests {
+		if got := unreadCountFromSeqs(context.Background(), "si_peer_a_test_user", tt.seqs); got != tt.want {
+			t.Errorf("%s: unread count = %d, want %d", tt.name, got, tt.want)
+		}
+	}
+}
@@ -12,6 +12,16 @@ func (g *Group) buildGroupMemberKey(groupID string, userID string) string {
 	return groupID + ":" + userID
 }
 
+// ClearGroupMemberCache drops all cached group members, they are loaded again on demand.
+func (g *Group) ClearGroupMemberCache() {
+	g.groupMemberCache.DeleteAll()
+}
+
+// ClearNotificationFilter drops the UUIDs of the notifications already handled.
+func (g *Group) ClearNotificationFilter() {
+	g.filter.Purge()
+}
+
 func (g *Group) GetGroupMembersInfoFunc(ctx context.Context, groupID string, userIDs []string,
 	fetchFunc func(ctx context.Context, missingKeys []string) ([]*model_struct.LocalGroupMember, error),
 ) (map[string]*model_struct.LocalGroupMember, error) {
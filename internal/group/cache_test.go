@@ -0,0 +1,28 @@
+package group
+
+import (
+	"testing"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+)
+
+func TestClearCaches(t *testing.T) {
+	g := NewGroup(nil)
+	member := &model_struct.LocalGroupMember{GroupID: "g1", UserID: "u1"}
+	g.groupMemberCache.Store(g.buildGroupMemberKey(member.GroupID, member.UserID), member)
+	if !g.filter.ShouldExecute("uuid") {
+		t.Fatal("a new notification was filtered")
+	}
+	if g.filter.ShouldExecute("uuid") {
+		t.Fatal("a repeated notification was not filtered")
+	}
+
+	g.ClearGroupMemberCache()
+	g.ClearNotificationFilter()
+	if _, ok := g.groupMemberCache.Load(g.buildGroupMemberKey(member.GroupID, member.UserID)); ok {
+		t.Error("the group member cache is not empty")
+	}
+	if !g.filter.ShouldExecute("uuid") {
+		t.Error("a notification is still filtered after clearing")
+	}
+}
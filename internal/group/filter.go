@@ -50,3 +50,10 @@ func (f *NotificationFilter) ExecuteIfNew(uuid string, fn func()) {
 		fn()
 	}
 }
+
+// Purge forgets every processed UUID, so that the next event with any UUID is executed.
+func (f *NotificationFilter) Purge() {
+	f.lock.Lock()
+	defer f.lock.Unlock()
+	f.data.Purge()
+}
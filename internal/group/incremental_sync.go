@@ -111,6 +111,9 @@ func (g *Group) syncGroupAndMember(ctx context.Context, groupID string, resp *gr
 		Local: func() ([]*model_struct.LocalGroupMember, error) {
 			return g.db.GetGroupMemberListByGroupID(ctx, groupID)
 		},
+		LocalByIDs: func(userIDs []string) ([]*model_struct.LocalGroupMember, error) {
+			return g.db.GetGroupSomeMemberInfo(ctx, groupID, userIDs)
+		},
 		ServerVersion: func() *group.GetIncrementalGroupMemberResp {
 			return resp
 		},
@@ -194,6 +197,9 @@ func (g *Group) onlineSyncGroupAndMember(ctx context.Context, groupID string, de
 		Local: func() ([]*model_struct.LocalGroupMember, error) {
 			return g.db.GetGroupMemberListByGroupID(ctx, groupID)
 		},
+		LocalByIDs: func(userIDs []string) ([]*model_struct.LocalGroupMember, error) {
+			return g.db.GetGroupSomeMemberInfo(ctx, groupID, userIDs)
+		},
 		ServerVersion: func() *group.GetIncrementalGroupMemberResp {
 			return &group.GetIncrementalGroupMemberResp{
 				Version:   version,
@@ -306,6 +312,9 @@ func (g *Group) IncrSyncJoinGroup(ctx context.Context) error {
 		Local: func() ([]*model_struct.LocalGroup, error) {
 			return g.db.GetJoinedGroupListDB(ctx)
 		},
+		LocalByIDs: func(groupIDs []string) ([]*model_struct.LocalGroup, error) {
+			return g.db.GetGroups(ctx, groupIDs)
+		},
 		Server: func(version *model_struct.LocalVersionSync) (*group.GetIncrementalJoinGroupResp, error) {
 			return g.getIncrementalJoinGroup(ctx, &group.GetIncrementalJoinGroupReq{
 				UserID:    g.loginUserID,
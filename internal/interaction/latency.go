@@ -0,0 +1,87 @@
+// Copyright © 2023 OpenIM SDK. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+package interaction
+
+import (
+	"sync"
+	"time"
+)
+
+type NetworkLatency struct {
+	// Latency round trip of the probe in milliseconds
+	Latency int64 `json:"latency"`
+	// Average moving average of heartbeat round trips in milliseconds
+	Average int64 `json:"average"`
+}
+
+type pendingPing struct {
+	sentAt time.Time
+	pong   chan time.Duration
+}
+
+// latency matches pongs to the pings that carried the same app data
+// and keeps a moving average of the round trips.
+type latency struct {
+	lock    sync.Mutex
+	pending map[string]pendingPing
+	average time.Duration
+}
+
+func newLatency() *latency {
+	return &latency{pending: make(map[string]pendingPing)}
+}
+
+func (l *latency) sent(appData string) <-chan time.Duration {
+	l.lock.Lock()
+	defer l.lock.Unlock()
+	now := time.Now()
+	for key, ping := range l.pending {
+		if now.Sub(ping.sentAt) > pongWait {
+			delete(l.pending, key)
+		}
+	}
+	ping := pendingPing{sentAt: now, pong: make(chan time.Duration, 1)}
+	l.pending[appData] = ping
+	return ping.pong
+}
+
+func (l *latency) cancel(appData string) {
+	l.lock.Lock()
+	defer l.lock.Unlock()
+	delete(l.pending, appData)
+}
+
+func (l *latency) received(appData string) {
+	l.lock.Lock()
+	defer l.lock.Unlock()
+	ping, ok := l.pending[appData]
+	if !ok {
+		return
+	}
+	delete(l.pending, appData)
+	rtt := time.Since(ping.sentAt)
+	if l.average == 0 {
+		l.average = rtt
+	} else {
+		l.average = (l.average*7 + rtt) / 8
+	}
+	ping.pong <- rtt
+}
+
+func (l *latency) Average() time.Duration {
+	l.lock.Lock()
+	defer l.lock.Unlock()
+	return l.average
+}
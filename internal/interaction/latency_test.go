@@ -0,0 +1,31 @@
+package interaction
+
+import (
+	"testing"
+	"time"
+)
+
+func TestLatency(t *testing.T) {
+	l := newLatency()
+	pong := l.sent("op1")
+	l.received("unknown")
+	select {
+	case <-pong:
+		t.Fatal("pong delivered for unmatched app data")
+	default:
+	}
+	time.Sleep(time.Millisecond * 5)
+	l.received("op1")
+	rtt := <-pong
+	if rtt < time.Millisecond*5 {
+		t.Fatalf("rtt too small: %s", rtt)
+	}
+	if l.Average() != rtt {
+		t.Fatalf("average %s, want %s", l.Average(), rtt)
+	}
+	l.cancel("op2")
+	l.received("op1")
+	if len(l.pending) != 0 {
+		t.Fatalf("pending not cleared: %v", l.pending)
+	}
+}
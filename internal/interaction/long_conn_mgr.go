@@ -98,6 +98,9 @@ type LongConnMgr struct {
 	encoder            Encoder
 	compressor         Compressor
 	reconnectStrategy  ReconnectStrategy
+	wsAddr             wsAddrSelector
+	latency            *latency
+	sendLimiter        sendLimiter
 
 	mutex        sync.Mutex
 	IsBackground bool
@@ -122,6 +125,7 @@ func NewLongConnMgr(ctx context.Context, userOnline func(map[string][]int32), pu
 		encoder:            NewGobEncoder(),
 		compressor:         NewGzipCompressor(),
 		reconnectStrategy:  NewExponentialRetry(),
+		latency:            newLatency(),
 		sub:                newSubscription(),
 	}
 	l.send = make(chan Message, 10)
@@ -148,6 +152,11 @@ func (c *LongConnMgr) ResumeForegroundTasks(ctx, fgCtx context.Context) {
 }
 
 func (c *LongConnMgr) SendReqWaitResp(ctx context.Context, m proto.Message, reqIdentifier int, resp proto.Message) error {
+	if reqIdentifier == constant.SendMsg {
+		if err := c.sendLimiter.wait(ctx, ccontext.Info(ctx).SendMsgRateLimit()); err != nil {
+			return err
+		}
+	}
 	data, err := proto.Marshal(m)
 	if err != nil {
 		return sdkerrs.ErrArgs
@@ -346,20 +355,42 @@ func (c *LongConnMgr) heartbeat(ctx context.Context, fgCtx context.Context) {
 
 }
 
-func (c *LongConnMgr) sendPingMessage(ctx context.Context) {
+// sendPingMessage returns a channel that receives the round trip once the matching pong arrives,
+// or nil if the ping could not be written.
+func (c *LongConnMgr) sendPingMessage(ctx context.Context) <-chan time.Duration {
 	c.connWrite.Lock()
 	defer c.connWrite.Unlock()
 	opid := utils.OperationIDGenerator()
 	log.ZDebug(ctx, "ping Message Started", "goroutine ID:", getGoroutineID(), "opid", opid)
 	if c.IsConnected() {
 		log.ZDebug(ctx, "ping Message Started isConnected", "goroutine ID:", getGoroutineID(), "opid", opid)
+		pong := c.latency.sent(opid)
 		c.conn.SetWriteDeadline(writeWait)
 		if err := c.conn.WriteMessage(PingMessage, []byte(opid)); err != nil {
 			log.ZWarn(ctx, "ping Message failed", err, "goroutine ID:", getGoroutineID(), "opid", opid)
-			return
+			c.latency.cancel(opid)
+			return nil
 		}
+		return pong
 	} else {
 		log.ZDebug(ctx, "ping Message failed, connection", "connStatus", c.GetConnectionStatus(), "goroutine ID:", getGoroutineID(), "opid", opid)
+		return nil
+	}
+}
+
+// GetNetworkLatency pings the gateway and reports the round trip together with the heartbeat average.
+func (c *LongConnMgr) GetNetworkLatency(ctx context.Context) (*NetworkLatency, error) {
+	pong := c.sendPingMessage(ctx)
+	if pong == nil {
+		return nil, sdkerrs.ErrNetwork.WrapMsg("connection is not established")
+	}
+	ctx, cancel := context.WithTimeout(ctx, pongWait)
+	defer cancel()
+	select {
+	case <-ctx.Done():
+		return nil, sdkerrs.ErrNetworkTimeOut
+	case rtt := <-pong:
+		return &NetworkLatency{Latency: rtt.Milliseconds(), Average: c.latency.Average().Milliseconds()}, nil
 	}
 }
 
@@ -635,6 +666,24 @@ func (c *LongConnMgr) IsConnected() bool {
 	return false
 
 }
+
+type ConnDiagnostics struct {
+	ConnectionStatus int    `json:"connectionStatus"`
+	WsAddr           string `json:"wsAddr"`
+	AverageLatency   int64  `json:"averageLatency"` // moving average of heartbeat round trips in milliseconds
+	ThrottledSends   int64  `json:"throttledSends"` // message sends delayed or rejected by sendMsgRateLimit
+}
+
+// Diagnostics reports the state of the gateway connection for troubleshooting.
+func (c *LongConnMgr) Diagnostics(ctx context.Context) *ConnDiagnostics {
+	return &ConnDiagnostics{
+		ConnectionStatus: c.GetConnectionStatus(),
+		WsAddr:           c.wsAddr.current(ccontext.Info(ctx).WsAddr()),
+		AverageLatency:   c.latency.Average().Milliseconds(),
+		ThrottledSends:   c.sendLimiter.Throttled(),
+	}
+}
+
 func (c *LongConnMgr) GetConnectionStatus() int {
 	c.w.Lock()
 	defer c.w.Unlock()
@@ -656,7 +705,7 @@ func (c *LongConnMgr) reConn(ctx context.Context, num *int) (needRecon bool, err
 	c.listener().OnConnecting()
 	c.SetConnectionStatus(Connecting)
 	url := fmt.Sprintf("%s?sendID=%s&token=%s&platformID=%d&operationID=%s&isBackground=%t",
-		ccontext.Info(ctx).WsAddr(), ccontext.Info(ctx).UserID(), ccontext.Info(ctx).Token(),
+		c.wsAddr.current(ccontext.Info(ctx).WsAddr()), ccontext.Info(ctx).UserID(), ccontext.Info(ctx).Token(),
 		ccontext.Info(ctx).PlatformID(), ccontext.Info(ctx).OperationID(), c.GetBackground())
 	if c.IsCompression {
 		url += fmt.Sprintf("&compression=%s", "gzip")
@@ -695,6 +744,7 @@ func (c *LongConnMgr) reConn(ctx context.Context, num *int) (needRecon bool, err
 				return true, err
 			}
 		}
+		c.wsAddr.next(ctx, ccontext.Info(ctx).WsAddr())
 		c.listener().OnConnectFailed(sdkerrs.NetworkError, err.Error())
 		return true, err
 	}
@@ -763,6 +813,7 @@ func (c *LongConnMgr) pingHandler(_ string) error {
 // when client send pong.
 func (c *LongConnMgr) pongHandler(appData string) error {
 	log.ZDebug(c.ctx, "server Pong Message Received", "appData", appData)
+	c.latency.received(appData)
 	if err := c.conn.SetReadDeadline(pongWait); err != nil {
 		return err
 	}
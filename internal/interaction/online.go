@@ -41,6 +41,11 @@ func (c *LongConnMgr) SubscribeUsersStatus(ctx context.Context, userIDs []string
 	return c.subscribeUsersStatus(ctx, userIDs)
 }
 
+// IsSubscribedUser reports whether the online status of userID is subscribed.
+func (c *LongConnMgr) IsSubscribedUser(userID string) bool {
+	return c.sub.isSubscribed(userID)
+}
+
 func (c *LongConnMgr) GetSubscribeUsersStatus(ctx context.Context) ([]*userPb.OnlineStatus, error) {
 	return c.subscribeUsersStatus(ctx, nil)
 }
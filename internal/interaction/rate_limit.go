@@ -0,0 +1,79 @@
+// Copyright © 2023 OpenIM SDK. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+package interaction
+
+import (
+	"context"
+	"sync"
+	"time"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/sdkerrs"
+	"github.com/openimsdk/openim-sdk-core/v3/sdk_struct"
+)
+
+// sendLimiter is a token bucket limiting how fast messages are sent to the gateway.
+type sendLimiter struct {
+	lock      sync.Mutex
+	tokens    float64
+	last      time.Time
+	throttled int64
+}
+
+// wait takes a token, waiting for one to become available unless the limit rejects instead.
+func (l *sendLimiter) wait(ctx context.Context, limit *sdk_struct.SendMsgRateLimit) error {
+	if limit == nil || limit.Rate <= 0 {
+		return nil
+	}
+	burst := float64(max(limit.Burst, 1))
+	counted := false
+	for {
+		l.lock.Lock()
+		now := time.Now()
+		if l.last.IsZero() {
+			l.tokens = burst
+		} else {
+			l.tokens = min(burst, l.tokens+now.Sub(l.last).Seconds()*limit.Rate)
+		}
+		l.last = now
+		if l.tokens >= 1 {
+			l.tokens--
+			l.lock.Unlock()
+			return nil
+		}
+		if !counted {
+			l.throttled++
+			counted = true
+		}
+		delay := time.Duration((1 - l.tokens) / limit.Rate * float64(time.Second))
+		l.lock.Unlock()
+		if limit.Reject {
+			return sdkerrs.ErrMsgThrottled
+		}
+		timer := time.NewTimer(delay)
+		select {
+		case <-ctx.Done():
+			timer.Stop()
+			return sdkerrs.ErrCtxDeadline
+		case <-timer.C:
+		}
+	}
+}
+
+// Throttled returns how many sends had to wait for, or were rejected by, the limit.
+func (l *sendLimiter) Throttled() int64 {
+	l.lock.Lock()
+	defer l.lock.Unlock()
+	return l.throttled
+}
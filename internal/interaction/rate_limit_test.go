@@ -0,0 +1,40 @@
+package interaction
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/sdkerrs"
+	"github.com/openimsdk/openim-sdk-core/v3/sdk_struct"
+)
+
+func TestSendLimiterReject(t *testing.T) {
+	var l sendLimiter
+	limit := &sdk_struct.SendMsgRateLimit{Rate: 1, Burst: 2, Reject: true}
+	for i := 0; i < 2; i++ {
+		if err := l.wait(context.Background(), limit); err != nil {
+			t.Fatalf("send %d: %v", i, err)
+		}
+	}
+	if err := l.wait(context.Background(), limit); !sdkerrs.ErrMsgThrottled.Is(err) {
+		t.Fatalf("expected throttled, got %v", err)
+	}
+	if l.Throttled() != 1 {
+		t.Fatalf("throttled %d", l.Throttled())
+	}
+}
+
+func TestSendLimiterWait(t *testing.T) {
+	var l sendLimiter
+	limit := &sdk_struct.SendMsgRateLimit{Rate: 20, Burst: 1}
+	start := time.Now()
+	for i := 0; i < 3; i++ {
+		if err := l.wait(context.Background(), limit); err != nil {
+			t.Fatal(err)
+		}
+	}
+	if elapsed := time.Since(start); elapsed < time.Millisecond*90 {
+		t.Fatalf("sends were not spaced out, elapsed %s", elapsed)
+	}
+}
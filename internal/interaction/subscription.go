@@ -120,6 +120,13 @@ func (s *subscription) setUserState(changes []*sdkws.SubUserOnlineStatusElem) ma
 	return change
 }
 
+func (s *subscription) isSubscribed(userID string) bool {
+	s.lock.Lock()
+	defer s.lock.Unlock()
+	_, ok := s.load[userID]
+	return ok
+}
+
 func (s *subscription) unsubscribe(userIDs []string) {
 	s.lock.Lock()
 	defer s.lock.Unlock()
@@ -29,3 +29,18 @@ func TestName(t *testing.T) {
 	sub.writeFailed(wait, errors.New("todo test"))
 
 }
+
+func TestIsSubscribed(t *testing.T) {
+	sub := newSubscription()
+	if sub.isSubscribed("1") {
+		t.Fatal("a user is subscribed before any read")
+	}
+	sub.getUserOnline([]string{"1"})
+	if !sub.isSubscribed("1") {
+		t.Fatal("the read user is not subscribed")
+	}
+	sub.unsubscribe([]string{"1"})
+	if sub.isSubscribed("1") {
+		t.Fatal("the user is still subscribed after unsubscribing")
+	}
+}
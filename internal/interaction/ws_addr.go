@@ -0,0 +1,65 @@
+// Copyright © 2023 OpenIM SDK. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+package interaction
+
+import (
+	"context"
+	"strings"
+	"sync"
+
+	"github.com/openimsdk/tools/log"
+)
+
+// SplitWsAddr splits the configured wsAddr into gateway endpoints.
+// Multiple gateways are given as a comma-separated list.
+func SplitWsAddr(wsAddr string) []string {
+	var addrs []string
+	for _, addr := range strings.Split(wsAddr, ",") {
+		if addr = strings.TrimSpace(addr); addr != "" {
+			addrs = append(addrs, addr)
+		}
+	}
+	return addrs
+}
+
+// wsAddrSelector keeps the gateway that last connected successfully and
+// rotates to the next one when dialing fails. The reconnect loop moves it
+// while diagnostics read it, so index is guarded by lock.
+type wsAddrSelector struct {
+	lock  sync.Mutex
+	index int
+}
+
+func (s *wsAddrSelector) current(wsAddr string) string {
+	addrs := SplitWsAddr(wsAddr)
+	if len(addrs) == 0 {
+		return wsAddr
+	}
+	s.lock.Lock()
+	defer s.lock.Unlock()
+	return addrs[s.index%len(addrs)]
+}
+
+func (s *wsAddrSelector) next(ctx context.Context, wsAddr string) {
+	addrs := SplitWsAddr(wsAddr)
+	if len(addrs) <= 1 {
+		return
+	}
+	s.lock.Lock()
+	s.index = (s.index + 1) % len(addrs)
+	addr := addrs[s.index]
+	s.lock.Unlock()
+	log.ZInfo(ctx, "switch ws gateway", "wsAddr", addr)
+}
@@ -0,0 +1,57 @@
+package interaction
+
+import (
+	"context"
+	"sync"
+	"testing"
+)
+
+func TestWsAddrSelector(t *testing.T) {
+	const wsAddr = "ws://10.0.0.1:10001, ws://10.0.0.2:10001"
+	if addrs := SplitWsAddr(wsAddr); len(addrs) != 2 {
+		t.Fatalf("split %q: got %v", wsAddr, addrs)
+	}
+	var s wsAddrSelector
+	if addr := s.current(wsAddr); addr != "ws://10.0.0.1:10001" {
+		t.Fatalf("current: got %s", addr)
+	}
+	s.next(context.Background(), wsAddr)
+	if addr := s.current(wsAddr); addr != "ws://10.0.0.2:10001" {
+		t.Fatalf("after next: got %s", addr)
+	}
+	s.next(context.Background(), wsAddr)
+	if addr := s.current(wsAddr); addr != "ws://10.0.0.1:10001" {
+		t.Fatalf("after wrap: got %s", addr)
+	}
+	var single wsAddrSelector
+	single.next(context.Background(), "ws://127.0.0.1:10001")
+	if addr := single.current("ws://127.0.0.1:10001"); addr != "ws://127.0.0.1:10001" {
+		t.Fatalf("single: got %s", addr)
+	}
+}
+
+// TestWsAddrSelectorConcurrent is meant for go test -race: reconnects move the selector while diagnostics read it.
+func TestWsAddrSelectorConcurrent(t *testing.T) {
+	const wsAddr = "ws://10.0.0.1:10001,ws://10.0.0.2:10001,ws://10.0.0.3:10001"
+	var (
+		s  wsAddrSelector
+		wg sync.WaitGroup
+	)
+	wg.Add(2)
+	go func() {
+		defer wg.Done()
+		for i := 0; i < 100; i++ {
+			s.next(context.Background(), wsAddr)
+		}
+	}()
+	go func() {
+		defer wg.Done()
+		for i := 0; i < 100; i++ {
+			s.current(wsAddr)
+		}
+	}()
+	wg.Wait()
+	if addr := s.current(wsAddr); addr != "ws://10.0.0.2:10001" {
+		t.Fatalf("after 100 moves: got %s", addr)
+	}
+}
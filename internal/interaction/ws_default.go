@@ -21,6 +21,8 @@ import (
 	"time"
 
 	"github.com/gorilla/websocket"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/network"
 )
 
 type Default struct {
@@ -70,7 +72,9 @@ func (d *Default) ReadMessage() (int, []byte, error) {
 }
 
 func (d *Default) Dial(urlStr string, requestHeader http.Header) (*http.Response, error) {
-	conn, httpResp, err := websocket.DefaultDialer.Dial(urlStr, requestHeader)
+	dialer := *websocket.DefaultDialer
+	dialer.TLSClientConfig = network.TLSConfig()
+	conn, httpResp, err := dialer.Dial(urlStr, requestHeader)
 	if err == nil {
 		d.conn = conn
 	}
@@ -21,6 +21,9 @@ func (r *Relation) IncrSyncFriends(ctx context.Context) error {
 		Local: func() ([]*model_struct.LocalFriend, error) {
 			return r.db.GetAllFriendList(ctx)
 		},
+		LocalByIDs: func(friendUserIDs []string) ([]*model_struct.LocalFriend, error) {
+			return r.db.GetFriendInfoList(ctx, friendUserIDs)
+		},
 		Server: func(version *model_struct.LocalVersionSync) (*relation.GetIncrementalFriendsResp, error) {
 			return r.getIncrementalFriends(ctx, &relation.GetIncrementalFriendsReq{
 				UserID:    r.loginUserID,
@@ -36,6 +36,10 @@ func GetMultipleConversation(callback open_im_sdk_callback.Base, operationID str
 	call(callback, operationID, IMUserContext.Conversation().GetMultipleConversation, conversationIDList)
 }
 
+func GetConversationRecvMessageOpt(callback open_im_sdk_callback.Base, operationID string, conversationIDList string) {
+	call(callback, operationID, IMUserContext.Conversation().GetConversationRecvMessageOpt, conversationIDList)
+}
+
 func SetConversation(callback open_im_sdk_callback.Base, operationID string, conversationID string, req string) {
 	call(callback, operationID, IMUserContext.Conversation().SetConversation, conversationID, req)
 }
@@ -48,6 +52,14 @@ func SetConversationDraft(callback open_im_sdk_callback.Base, operationID string
 	call(callback, operationID, IMUserContext.Conversation().SetConversationDraft, conversationID, draftText)
 }
 
+func SetConversationStructuredDraft(callback open_im_sdk_callback.Base, operationID string, conversationID string, draft string) {
+	call(callback, operationID, IMUserContext.Conversation().SetConversationStructuredDraft, conversationID, draft)
+}
+
+func GetConversationStructuredDraft(callback open_im_sdk_callback.Base, operationID string, conversationID string) {
+	call(callback, operationID, IMUserContext.Conversation().GetConversationStructuredDraft, conversationID)
+}
+
 func GetTotalUnreadMsgCount(callback open_im_sdk_callback.Base, operationID string) {
 	call(callback, operationID, IMUserContext.Conversation().GetTotalUnreadMsgCount)
 }
@@ -170,6 +182,62 @@ func MarkAllConversationMessageAsRead(callback open_im_sdk_callback.Base, operat
 	call(callback, operationID, IMUserContext.Conversation().MarkAllConversationMessageAsRead)
 }
 
+func RecalculateUnreadCount(callback open_im_sdk_callback.Base, operationID string, conversationID string) {
+	call(callback, operationID, IMUserContext.Conversation().RecalculateUnreadCount, conversationID)
+}
+
+func RecalculateAllUnreadCount(callback open_im_sdk_callback.Base, operationID string) {
+	call(callback, operationID, IMUserContext.Conversation().RecalculateAllUnreadCount)
+}
+
+func AddFavorite(callback open_im_sdk_callback.Base, operationID string, conversationID string, clientMsgID string) {
+	call(callback, operationID, IMUserContext.Conversation().AddFavorite, conversationID, clientMsgID)
+}
+
+func RemoveFavorite(callback open_im_sdk_callback.Base, operationID string, conversationID string, clientMsgID string) {
+	call(callback, operationID, IMUserContext.Conversation().RemoveFavorite, conversationID, clientMsgID)
+}
+
+func GetFavoriteList(callback open_im_sdk_callback.Base, operationID string, offset int, count int) {
+	call(callback, operationID, IMUserContext.Conversation().GetFavoriteList, offset, count)
+}
+
+func StarMessage(callback open_im_sdk_callback.Base, operationID string, conversationID string, clientMsgID string, isStarred bool) {
+	call(callback, operationID, IMUserContext.Conversation().StarMessage, conversationID, clientMsgID, isStarred)
+}
+
+func GetStarredMessages(callback open_im_sdk_callback.Base, operationID string, conversationID string) {
+	call(callback, operationID, IMUserContext.Conversation().GetStarredMessages, conversationID)
+}
+
+func CreateConversationFolder(callback open_im_sdk_callback.Base, operationID string, name string) {
+	call(callback, operationID, IMUserContext.Conversation().CreateConversationFolder, name)
+}
+
+func RenameConversationFolder(callback open_im_sdk_callback.Base, operationID string, folderID string, name string) {
+	call(callback, operationID, IMUserContext.Conversation().RenameConversationFolder, folderID, name)
+}
+
+func DeleteConversationFolder(callback open_im_sdk_callback.Base, operationID string, folderID string) {
+	call(callback, operationID, IMUserContext.Conversation().DeleteConversationFolder, folderID)
+}
+
+func GetConversationFolders(callback open_im_sdk_callback.Base, operationID string) {
+	call(callback, operationID, IMUserContext.Conversation().GetConversationFolders)
+}
+
+func AddConversationsToFolder(callback open_im_sdk_callback.Base, operationID string, folderID string, conversationIDList string) {
+	call(callback, operationID, IMUserContext.Conversation().AddConversationsToFolder, folderID, conversationIDList)
+}
+
+func RemoveConversationsFromFolder(callback open_im_sdk_callback.Base, operationID string, folderID string, conversationIDList string) {
+	call(callback, operationID, IMUserContext.Conversation().RemoveConversationsFromFolder, folderID, conversationIDList)
+}
+
+func GetConversationListByFolder(callback open_im_sdk_callback.Base, operationID string, folderID string, offset int, count int) {
+	call(callback, operationID, IMUserContext.Conversation().GetConversationListByFolder, folderID, offset, count)
+}
+
 func MarkMessagesAsReadByMsgID(callback open_im_sdk_callback.Base, operationID string, conversationID string, clientMsgIDs string) {
 	call(callback, operationID, IMUserContext.Conversation().MarkMessagesAsReadByMsgID, conversationID, clientMsgIDs)
 }
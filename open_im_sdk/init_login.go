@@ -16,18 +16,25 @@ package open_im_sdk
 
 import (
 	"context"
+	"crypto/tls"
+	"encoding/base64"
 	"encoding/json"
 	"fmt"
 	"strings"
+	"time"
 
+	"github.com/openimsdk/openim-sdk-core/v3/internal/interaction"
 	"github.com/openimsdk/openim-sdk-core/v3/open_im_sdk_callback"
 	"github.com/openimsdk/openim-sdk-core/v3/pkg/ccontext"
 	"github.com/openimsdk/openim-sdk-core/v3/pkg/cliconf"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/network"
 	pbConstant "github.com/openimsdk/protocol/constant"
 
 	"github.com/openimsdk/openim-sdk-core/v3/sdk_struct"
 	"github.com/openimsdk/openim-sdk-core/v3/version"
 
+	"github.com/openimsdk/tools/errs"
 	"github.com/openimsdk/tools/log"
 	"github.com/openimsdk/tools/mcontext"
 )
@@ -68,6 +75,26 @@ func InitSDK(listener open_im_sdk_callback.OnConnListener, operationID string, c
 		log.ZError(ctx, "ws is ws protocol, ws format is invalid", nil)
 		return false
 	}
+	var tlsConfig *tls.Config
+	if configArgs.TLSConfig != nil {
+		var err error
+		tlsConfig, err = network.NewTLSConfig(configArgs.TLSConfig)
+		if err != nil {
+			log.ZError(ctx, "tls config is invalid", err)
+			return false
+		}
+	}
+	// always applied, so a config without TLS settings does not keep those of a previous InitSDK
+	network.SetTLSConfig(tlsConfig)
+	retryPolicy := network.DefaultRetryPolicy()
+	if configArgs.ApiRetry != nil {
+		retryPolicy = network.RetryPolicy{
+			Attempts:   configArgs.ApiRetry.Attempts,
+			Backoff:    time.Duration(configArgs.ApiRetry.BackoffMs) * time.Millisecond,
+			MaxBackoff: time.Duration(configArgs.ApiRetry.MaxBackoffMs) * time.Millisecond,
+		}
+	}
+	network.SetRetryPolicy(retryPolicy)
 
 	log.ZInfo(ctx, "InitSDK info", "config", configArgs)
 	if listener == nil || config == "" {
@@ -102,6 +129,22 @@ func NetworkStatusChanged(callback open_im_sdk_callback.Base, operationID string
 	call(callback, operationID, IMUserContext.NetworkStatusChanged)
 }
 
+func GetNetworkLatency(callback open_im_sdk_callback.Base, operationID string) {
+	call(callback, operationID, IMUserContext.GetNetworkLatency)
+}
+
+func GetDiagnostics(callback open_im_sdk_callback.Base, operationID string) {
+	call(callback, operationID, IMUserContext.GetDiagnostics)
+}
+
+func ClearMemoryCaches(callback open_im_sdk_callback.Base, operationID string) {
+	call(callback, operationID, IMUserContext.ClearMemoryCaches)
+}
+
+func GetTokenInfo(callback open_im_sdk_callback.Base, operationID string) {
+	call(callback, operationID, IMUserContext.GetTokenInfo)
+}
+
 func GetLoginStatus(operationID string) int {
 	return IMUserContext.GetLoginStatus(ccontext.WithOperationID(context.Background(), operationID))
 }
@@ -121,6 +164,126 @@ func (u *UserContext) SetAppBackgroundStatus(ctx context.Context, isBackground b
 func (u *UserContext) NetworkStatusChanged(ctx context.Context) {
 	u.longConnMgr.Close(ctx)
 }
+func (u *UserContext) GetNetworkLatency(ctx context.Context) (*interaction.NetworkLatency, error) {
+	return u.longConnMgr.GetNetworkLatency(ctx)
+}
+
+// Diagnostics is a snapshot of the SDK state used to troubleshoot field issues.
+type Diagnostics struct {
+	SdkVersion          string                       `json:"sdkVersion"`
+	LoginUserID         string                       `json:"loginUserID"`
+	LoginStatus         int                          `json:"loginStatus"`
+	SendingMessageCount int                          `json:"sendingMessageCount"`
+	Connection          *interaction.ConnDiagnostics `json:"connection"`
+	// SyncVersions is the last synced incremental version per table, 0 if never synced.
+	SyncVersions map[string]uint64 `json:"syncVersions"`
+	// RowCounts is the number of local rows per table.
+	RowCounts map[string]int64 `json:"rowCounts"`
+}
+
+func (u *UserContext) GetDiagnostics(ctx context.Context) (*Diagnostics, error) {
+	sendingMessages, err := u.db.GetAllSendingMessages(ctx)
+	if err != nil {
+		return nil, err
+	}
+	syncVersions := make(map[string]uint64)
+	for _, tableName := range []string{
+		model_struct.LocalFriend{}.TableName(),
+		model_struct.LocalGroup{}.TableName(),
+		model_struct.LocalConversation{}.TableName(),
+	} {
+		lvs, err := u.db.GetVersionSync(ctx, tableName, u.info.UserID)
+		if err != nil {
+			if !errs.ErrRecordNotFound.Is(err) {
+				return nil, err
+			}
+			lvs = &model_struct.LocalVersionSync{}
+		}
+		syncVersions[tableName] = lvs.Version
+	}
+	friendCount, err := u.db.GetFriendListCount(ctx)
+	if err != nil {
+		return nil, err
+	}
+	groups, err := u.db.GetJoinedGroupListDB(ctx)
+	if err != nil {
+		return nil, err
+	}
+	conversationIDs, err := u.db.GetAllConversationIDList(ctx)
+	if err != nil {
+		return nil, err
+	}
+	return &Diagnostics{
+		SdkVersion:          version.Version,
+		LoginUserID:         u.info.UserID,
+		LoginStatus:         u.getLoginStatus(ctx),
+		SendingMessageCount: len(sendingMessages),
+		Connection:          u.longConnMgr.Diagnostics(ctx),
+		SyncVersions:        syncVersions,
+		RowCounts: map[string]int64{
+			model_struct.LocalFriend{}.TableName():       friendCount,
+			model_struct.LocalGroup{}.TableName():        int64(len(groups)),
+			model_struct.LocalConversation{}.TableName(): int64(len(conversationIDs)),
+		},
+	}, nil
+}
+
+// ClearMemoryCaches drops the in-memory user, group member and conversation caches, the set of
+// notifications already handled and the pending video thumbnails, later lookups read the local
+// database or the server again.
+func (u *UserContext) ClearMemoryCaches(ctx context.Context) error {
+	u.user.UserCache().DeleteAll()
+	u.group.ClearGroupMemberCache()
+	u.group.ClearNotificationFilter()
+	u.conversation.ClearMemoryCaches(ctx)
+	log.ZInfo(ctx, "memory caches cleared")
+	return nil
+}
+
+// TokenInfo describes the token of the current session.
+// ExpireTime is a unix time in seconds, RemainingSeconds is 0 once the token has expired.
+type TokenInfo struct {
+	UserID           string `json:"userID"`
+	PlatformID       int32  `json:"platformID"`
+	ExpireTime       int64  `json:"expireTime"`
+	RemainingSeconds int64  `json:"remainingSeconds"`
+}
+
+// GetTokenInfo reads the claims of the login token locally, the token is not verified with the server.
+func (u *UserContext) GetTokenInfo(ctx context.Context) (*TokenInfo, error) {
+	return parseTokenInfo(u.info.Token, time.Now())
+}
+
+// parseTokenInfo decodes the payload of a JWT issued by the server, the signature is not checked.
+func parseTokenInfo(token string, now time.Time) (*TokenInfo, error) {
+	parts := strings.Split(token, ".")
+	if len(parts) != 3 {
+		return nil, errs.New("token is not a JWT").Wrap()
+	}
+	payload, err := base64.RawURLEncoding.DecodeString(strings.TrimRight(parts[1], "="))
+	if err != nil {
+		return nil, errs.WrapMsg(err, "decode token payload failed")
+	}
+	var claims struct {
+		UserID     string `json:"UserID"`
+		PlatformID int32  `json:"PlatformID"`
+		ExpiresAt  int64  `json:"exp"`
+	}
+	if err := json.Unmarshal(payload, &claims); err != nil {
+		return nil, errs.WrapMsg(err, "unmarshal token claims failed")
+	}
+	remaining := claims.ExpiresAt - now.Unix()
+	if remaining < 0 {
+		remaining = 0
+	}
+	return &TokenInfo{
+		UserID:           claims.UserID,
+		PlatformID:       claims.PlatformID,
+		ExpireTime:       claims.ExpiresAt,
+		RemainingSeconds: remaining,
+	}, nil
+}
+
 func (u *UserContext) GetLoginStatus(ctx context.Context) int {
 	return u.getLoginStatus(ctx)
 }
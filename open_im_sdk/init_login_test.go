@@ -0,0 +1,40 @@
+package open_im_sdk
+
+import (
+	"encoding/base64"
+	"testing"
+	"time"
+)
+
+func testToken(payload string) string {
+	return "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9." + base64.RawURLEncoding.EncodeToString([]byte(payload)) + ".signature"
+}
+
+func TestParseTokenInfo(t *testing.T) {
+	now := time.Unix(1700000000, 0)
+	tests := []struct {
+		name          string
+		expireTime    string
+		wantRemaining int64
+	}{
+		{"valid", "1700003600", 3600},
+		{"expires now", "1700000000", 0},
+		{"expired", "1699990000", 0},
+	}
+	for _, tt := range tests {
+		token := testToken(`{"UserID":"test_user","PlatformID":2,"exp":` + tt.expireTime + `,"iat":1690000000}`)
+		info, err := parseTokenInfo(token, now)
+		if err != nil {
+			t.Fatalf("%s: %v", tt.name, err)
+		}
+		if info.UserID != "test_user" || info.PlatformID != 2 || info.RemainingSeconds != tt.wantRemaining {
+			t.Errorf("%s: token info = %+v, want remaining %d", tt.name, info, tt.wantRemaining)
+		}
+	}
+
+	for _, token := range []string{"", "not a token", "a.!!!.c", testToken("not json")} {
+		if _, err := parseTokenInfo(token, now); err == nil {
+			t.Errorf("token %q was parsed", token)
+		}
+	}
+}
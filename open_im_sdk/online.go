@@ -1,7 +1,11 @@
 package open_im_sdk
 
 import (
+	"context"
+
 	"github.com/openimsdk/openim-sdk-core/v3/open_im_sdk_callback"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/constant"
+	userPb "github.com/openimsdk/protocol/user"
 )
 
 // SubscribeUsersStatus Presence status of subscribed users.
@@ -23,3 +27,25 @@ func GetSubscribeUsersStatus(callback open_im_sdk_callback.Base, operationID str
 func GetUserStatus(callback open_im_sdk_callback.Base, operationID string, userIDs string) {
 	call(callback, operationID, IMUserContext.LongConnMgr().SubscribeUsersStatus, userIDs)
 }
+
+// GetOnlineDevices Get the platforms the login user is online on. Subscribe to the login user with
+// SubscribeUsersStatus to have later changes reported by OnUserStatusChanged.
+func GetOnlineDevices(callback open_im_sdk_callback.Base, operationID string) {
+	call(callback, operationID, IMUserContext.GetOnlineDevices)
+}
+
+func (u *UserContext) GetOnlineDevices(ctx context.Context) (*userPb.OnlineStatus, error) {
+	userIDs := []string{u.info.UserID}
+	// the status is only subscribed for this read, unless it was subscribed before
+	if !u.longConnMgr.IsSubscribedUser(u.info.UserID) {
+		defer func() { _ = u.longConnMgr.UnsubscribeUsersStatus(ctx, userIDs) }()
+	}
+	status, err := u.longConnMgr.SubscribeUsersStatus(ctx, userIDs)
+	if err != nil {
+		return nil, err
+	}
+	if len(status) == 0 {
+		return &userPb.OnlineStatus{UserID: u.info.UserID, Status: constant.Offline, PlatformIDs: []int32{}}, nil
+	}
+	return status[0], nil
+}
@@ -440,6 +440,7 @@ func (u *UserContext) run(ctx context.Context) {
 	u.longConnMgr.Run(ctx, u.fgCtx)
 	go u.msgSyncer.DoListener(ctx)
 	go common.DoListener(u.ctx, u.conversation)
+	go u.conversation.DoMsgDestruct(u.ctx)
 	go u.logoutListener(ctx)
 }
 
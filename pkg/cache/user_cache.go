@@ -3,6 +3,8 @@ package cache
 import (
 	"context"
 	"fmt"
+	"sync"
+	"time"
 
 	"github.com/openimsdk/openim-sdk-core/v3/pkg/sdkerrs"
 	"github.com/openimsdk/tools/utils/datautil"
@@ -10,6 +12,8 @@ import (
 
 const (
 	SpecialUserPrefix = "special_"
+	// defaultNotFoundExpire how long a key the server did not know is answered from the cache
+	defaultNotFoundExpire = time.Minute * 5
 )
 
 func NewUserCache[K comparable, V any](
@@ -24,6 +28,8 @@ func NewUserCache[K comparable, V any](
 		batchDBFunc:  batchDBFunc,
 		singleDBFunc: singleDBFunc,
 		queryFunc:    queryFunc,
+
+		notFoundExpire: defaultNotFoundExpire,
 	}
 }
 
@@ -33,21 +39,63 @@ type UserCache[K comparable, V any] struct {
 	batchDBFunc  func(ctx context.Context, keys []K) ([]V, error)
 	singleDBFunc func(ctx context.Context, keys K) (V, error)
 	queryFunc    func(ctx context.Context, keys []K) ([]V, error)
+	// notFound key -> expire time, keeps unknown users from being queried on every message
+	notFound       sync.Map
+	notFoundExpire time.Duration
+}
+
+func (m *UserCache[K, V]) isNotFound(key K) bool {
+	expire, ok := m.notFound.Load(key)
+	if !ok {
+		return false
+	}
+	if time.Now().After(expire.(time.Time)) {
+		m.notFound.Delete(key)
+		return false
+	}
+	return true
+}
+
+func (m *UserCache[K, V]) markNotFound(keys ...K) {
+	expire := time.Now().Add(m.notFoundExpire)
+	for _, key := range keys {
+		m.notFound.Store(key, expire)
+	}
+}
+
+func (m *UserCache[K, V]) Delete(key K) {
+	m.notFound.Delete(key)
+	m.Cache.Delete(key)
+}
+
+func (m *UserCache[K, V]) DeleteAll() {
+	m.notFound.Range(func(key, _ any) bool {
+		m.notFound.Delete(key)
+		return true
+	})
+	m.Cache.DeleteAll()
 }
 
 func (m *UserCache[K, V]) BatchFetch(ctx context.Context, keys []K) (map[K]V, error) {
 	var (
-		res       = make(map[K]V)
-		queryKeys []K
+		res          = make(map[K]V)
+		queryKeys    []K
+		notFoundKeys []K
 	)
 
 	for _, key := range keys {
 		if data, ok := m.Load(key); ok {
 			res[key] = data
+		} else if m.isNotFound(key) {
+			notFoundKeys = append(notFoundKeys, key)
 		} else {
-			queryKeys = append(queryKeys, keys...)
+			queryKeys = append(queryKeys, key)
 		}
 	}
+	// answer like batchFetch does when the server knows none of the queried keys
+	if len(queryKeys) == 0 && len(notFoundKeys) > 0 {
+		return nil, sdkerrs.ErrUserIDNotFound.WrapMsg("fetch data not found", "keys", notFoundKeys)
+	}
 
 	writeData, err := m.batchFetch(ctx, queryKeys)
 	if err != nil {
@@ -68,6 +116,9 @@ func (m *UserCache[K, V]) Fetch(ctx context.Context, key K) (V, error) {
 	if data, ok := m.Load(key); ok {
 		return data, nil
 	}
+	if m.isNotFound(key) {
+		return nilData, sdkerrs.ErrUserIDNotFound.WrapMsg("fetch data not found", "key", key)
+	}
 
 	fetchedData, err := m.fetch(ctx, key)
 	if err != nil {
@@ -104,6 +155,7 @@ func (m *UserCache[K, V]) batchFetch(ctx context.Context, keys []K) ([]V, error)
 		if err != nil {
 			return nil, err
 		}
+		m.markNotFound(datautil.SliceSubAny(queryKeys, queryData, m.getKeyFunc)...)
 		if len(queryData) == 0 {
 			return writeData, sdkerrs.ErrUserIDNotFound.WrapMsg("fetch data not found", "keys", keys)
 		}
@@ -128,6 +180,7 @@ func (m *UserCache[K, V]) fetch(ctx context.Context, key K) (V, error) {
 		if len(queryData) > 0 {
 			return queryData[0], nil
 		}
+		m.markNotFound(key)
 		return writeData, sdkerrs.ErrUserIDNotFound.WrapMsg("fetch data not found", "key", key)
 	}
 	return writeData, nil
@@ -0,0 +1,74 @@
+package cache
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/sdkerrs"
+	"github.com/openimsdk/tools/utils/datautil"
+)
+
+type testUser struct {
+	UserID string
+}
+
+func TestUserCacheNotFound(t *testing.T) {
+	ctx := context.Background()
+	known := map[string]bool{"known": true}
+	var queried []string
+	c := NewUserCache[string, *testUser](
+		func(value *testUser) string { return value.UserID },
+		nil,
+		nil,
+		func(ctx context.Context, keys []string) ([]*testUser, error) {
+			queried = append(queried, keys...)
+			var res []*testUser
+			for _, key := range datautil.Distinct(keys) {
+				if known[key] {
+					res = append(res, &testUser{UserID: key})
+				}
+			}
+			return res, nil
+		},
+	)
+	c.notFoundExpire = time.Millisecond * 50
+
+	// miss: the server is asked and does not know the user
+	if _, err := c.BatchFetch(ctx, []string{"unknown"}); !sdkerrs.ErrUserIDNotFound.Is(err) {
+		t.Fatalf("miss: err = %v, want ErrUserIDNotFound", err)
+	}
+	if len(queried) != 1 {
+		t.Fatalf("miss: queried %v, want one query", queried)
+	}
+
+	// negative cache hit: same error, the server is not asked again
+	if _, err := c.BatchFetch(ctx, []string{"unknown"}); !sdkerrs.ErrUserIDNotFound.Is(err) {
+		t.Fatalf("negative cache hit: err = %v, want ErrUserIDNotFound", err)
+	}
+	if _, err := c.Fetch(ctx, "unknown"); !sdkerrs.ErrUserIDNotFound.Is(err) {
+		t.Fatalf("negative cache hit: Fetch err = %v, want ErrUserIDNotFound", err)
+	}
+	if len(queried) != 1 {
+		t.Fatalf("negative cache hit: queried %v, want no new query", queried)
+	}
+
+	// known users are still returned next to a negatively cached one
+	res, err := c.BatchFetch(ctx, []string{"unknown", "known"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(res) != 1 || res["known"] == nil {
+		t.Fatalf("mixed: got %v, want only the known user", res)
+	}
+
+	// expiry: the server is asked again once the TTL has passed
+	time.Sleep(c.notFoundExpire * 2)
+	queried = nil
+	if _, err := c.BatchFetch(ctx, []string{"unknown"}); !sdkerrs.ErrUserIDNotFound.Is(err) {
+		t.Fatalf("expired: err = %v, want ErrUserIDNotFound", err)
+	}
+	if len(queried) != 1 || queried[0] != "unknown" {
+		t.Fatalf("expired: queried %v, want the unknown user queried again", queried)
+	}
+}
@@ -48,6 +48,10 @@ type ContextInfo interface {
 	WsAddr() string
 	DataDir() string
 	LogLevel() uint32
+	DefaultSendOptions() map[string]bool
+	MaxMsgContentSize() int
+	ConvertOversizedTextToFile() bool
+	SendMsgRateLimit() *sdk_struct.SendMsgRateLimit
 	OperationID() string
 }
 
@@ -117,6 +121,22 @@ func (i *info) LogLevel() uint32 {
 	return i.conf.LogLevel
 }
 
+func (i *info) DefaultSendOptions() map[string]bool {
+	return i.conf.DefaultSendOptions
+}
+
+func (i *info) MaxMsgContentSize() int {
+	return i.conf.MaxMsgContentSize
+}
+
+func (i *info) ConvertOversizedTextToFile() bool {
+	return i.conf.ConvertOversizedTextToFile
+}
+
+func (i *info) SendMsgRateLimit() *sdk_struct.SendMsgRateLimit {
+	return i.conf.SendMsgRateLimit
+}
+
 func (i *info) OperationID() string {
 	return mcontext.GetOperationID(i.ctx)
 }
@@ -346,6 +346,24 @@ func (d *DataBase) GetUnreadMessage(ctx context.Context, conversationID string)
 	return msgs, err
 }
 
+// GetBurnedPrivateChatMessages returns the read private chat messages whose hasReadTime + burnDuration in attached_info
+// is not after now. Rows without valid JSON in attached_info are skipped.
+func (d *DataBase) GetBurnedPrivateChatMessages(ctx context.Context, conversationID string, now int64) (msgs []*model_struct.LocalChatLog, err error) {
+	if err = d.initChatLog(ctx, conversationID); err != nil {
+		log.ZWarn(ctx, "initChatLog err", err)
+		return nil, err
+	}
+	d.mRWMutex.RLock()
+	defer d.mRWMutex.RUnlock()
+	err = errs.WrapMsg(d.conn.WithContext(ctx).Table(utils.GetConversationTableName(conversationID)).
+		Where("CASE WHEN json_valid(attached_info) THEN "+
+			"json_extract(attached_info, '$.isPrivateChat') = 1 AND json_extract(attached_info, '$.hasReadTime') > 0 AND "+
+			"json_extract(attached_info, '$.hasReadTime') + json_extract(attached_info, '$.burnDuration') * 1000 <= ? "+
+			"ELSE 0 END", now).
+		Find(&msgs).Error, "GetBurnedPrivateChatMessages failed")
+	return msgs, err
+}
+
 func (d *DataBase) MarkConversationMessageAsReadBySeqs(ctx context.Context, conversationID string, seqs []int64) (rowsAffected int64, err error) {
 	d.mRWMutex.Lock()
 	defer d.mRWMutex.Unlock()
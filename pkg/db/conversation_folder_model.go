@@ -0,0 +1,92 @@
+// Copyright © 2023 OpenIM SDK. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//go:build !js
+// +build !js
+
+package db
+
+import (
+	"context"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/tools/errs"
+	"gorm.io/gorm"
+)
+
+func (d *DataBase) InsertConversationFolder(ctx context.Context, folder *model_struct.LocalConversationFolder) error {
+	d.mRWMutex.Lock()
+	defer d.mRWMutex.Unlock()
+	return errs.WrapMsg(d.conn.WithContext(ctx).Create(folder).Error, "InsertConversationFolder failed")
+}
+
+func (d *DataBase) UpdateConversationFolder(ctx context.Context, folder *model_struct.LocalConversationFolder) error {
+	d.mRWMutex.Lock()
+	defer d.mRWMutex.Unlock()
+	return errs.WrapMsg(d.conn.WithContext(ctx).Model(folder).Select("name").Updates(folder).Error, "UpdateConversationFolder failed")
+}
+
+// DeleteConversationFolder deletes the folder together with its conversation assignments.
+func (d *DataBase) DeleteConversationFolder(ctx context.Context, folderID string) error {
+	d.mRWMutex.Lock()
+	defer d.mRWMutex.Unlock()
+	return errs.WrapMsg(d.conn.WithContext(ctx).Transaction(func(tx *gorm.DB) error {
+		if err := tx.Where("folder_id = ?", folderID).Delete(&model_struct.LocalFolderConversation{}).Error; err != nil {
+			return err
+		}
+		return tx.Where("folder_id = ?", folderID).Delete(&model_struct.LocalConversationFolder{}).Error
+	}), "DeleteConversationFolder failed")
+}
+
+func (d *DataBase) GetConversationFolder(ctx context.Context, folderID string) (*model_struct.LocalConversationFolder, error) {
+	d.mRWMutex.RLock()
+	defer d.mRWMutex.RUnlock()
+	var folder model_struct.LocalConversationFolder
+	return &folder, errs.WrapMsg(d.conn.WithContext(ctx).Where("folder_id = ?", folderID).Take(&folder).Error, "GetConversationFolder failed, folderID: "+folderID)
+}
+
+func (d *DataBase) GetAllConversationFolders(ctx context.Context) ([]*model_struct.LocalConversationFolder, error) {
+	d.mRWMutex.RLock()
+	defer d.mRWMutex.RUnlock()
+	var folders []*model_struct.LocalConversationFolder
+	return folders, errs.WrapMsg(d.conn.WithContext(ctx).Order("create_time ASC").Find(&folders).Error, "GetAllConversationFolders failed")
+}
+
+func (d *DataBase) BatchInsertFolderConversations(ctx context.Context, folderConversations []*model_struct.LocalFolderConversation) error {
+	if len(folderConversations) == 0 {
+		return nil
+	}
+	d.mRWMutex.Lock()
+	defer d.mRWMutex.Unlock()
+	return errs.WrapMsg(d.conn.WithContext(ctx).Create(folderConversations).Error, "BatchInsertFolderConversations failed")
+}
+
+func (d *DataBase) DeleteFolderConversations(ctx context.Context, folderID string, conversationIDs []string) error {
+	if len(conversationIDs) == 0 {
+		return nil
+	}
+	d.mRWMutex.Lock()
+	defer d.mRWMutex.Unlock()
+	return errs.WrapMsg(d.conn.WithContext(ctx).Where("folder_id = ? and conversation_id in ?", folderID, conversationIDs).Delete(&model_struct.LocalFolderConversation{}).Error, "DeleteFolderConversations failed")
+}
+
+// GetConversationListByFolderDB pages the conversations of a folder in the same order as GetConversationListSplitDB.
+func (d *DataBase) GetConversationListByFolderDB(ctx context.Context, folderID string, offset, count int) ([]*model_struct.LocalConversation, error) {
+	d.mRWMutex.RLock()
+	defer d.mRWMutex.RUnlock()
+	var conversationList []*model_struct.LocalConversation
+	return conversationList, errs.WrapMsg(d.conn.WithContext(ctx).
+		Where("latest_msg_send_time > ? and conversation_id in (?)", 0, d.conn.Model(&model_struct.LocalFolderConversation{}).Select("conversation_id").Where("folder_id = ?", folderID)).
+		Order(conversationListOrder).Offset(offset).Limit(count).Find(&conversationList).Error, "GetConversationListByFolderDB failed")
+}
@@ -0,0 +1,115 @@
+package db
+
+import (
+	"context"
+	"testing"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+)
+
+func folderConversationIDs(t *testing.T, db *DataBase, folderID string) []string {
+	t.Helper()
+	conversations, err := db.GetConversationListByFolderDB(context.Background(), folderID, 0, 10)
+	if err != nil {
+		t.Fatal(err)
+	}
+	ids := make([]string, 0, len(conversations))
+	for _, conversation := range conversations {
+		ids = append(ids, conversation.ConversationID)
+	}
+	return ids
+}
+
+func TestConversationFolders(t *testing.T) {
+	ctx := context.Background()
+	db, err := NewDataBase(ctx, "1695766238", t.TempDir(), 6)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer func() { _ = db.Close(ctx) }()
+
+	conversations := []*model_struct.LocalConversation{
+		{ConversationID: "si_1", LatestMsgSendTime: 3},
+		{ConversationID: "si_2", LatestMsgSendTime: 2},
+		{ConversationID: "si_3", LatestMsgSendTime: 1},
+	}
+	if err := db.BatchInsertConversationList(ctx, conversations); err != nil {
+		t.Fatal(err)
+	}
+
+	// create and rename
+	work := &model_struct.LocalConversationFolder{FolderID: "work", Name: "work", CreateTime: 1}
+	family := &model_struct.LocalConversationFolder{FolderID: "family", Name: "family", CreateTime: 2}
+	for _, folder := range []*model_struct.LocalConversationFolder{work, family} {
+		if err := db.InsertConversationFolder(ctx, folder); err != nil {
+			t.Fatal(err)
+		}
+	}
+	work.Name = "office"
+	if err := db.UpdateConversationFolder(ctx, work); err != nil {
+		t.Fatal(err)
+	}
+	folder, err := db.GetConversationFolder(ctx, "work")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if folder.Name != "office" || folder.CreateTime != 1 {
+		t.Fatalf("renamed folder = %+v, want name office and create time kept", folder)
+	}
+	folders, err := db.GetAllConversationFolders(ctx)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(folders) != 2 || folders[0].FolderID != "work" || folders[1].FolderID != "family" {
+		t.Fatalf("folders = %+v, want work then family", folders)
+	}
+
+	// assign, a conversation may be in several folders
+	if err := db.BatchInsertFolderConversations(ctx, []*model_struct.LocalFolderConversation{
+		{FolderID: "work", ConversationID: "si_3"},
+		{FolderID: "work", ConversationID: "si_1"},
+		{FolderID: "family", ConversationID: "si_1"},
+	}); err != nil {
+		t.Fatal(err)
+	}
+	if ids := folderConversationIDs(t, db, "work"); len(ids) != 2 || ids[0] != "si_1" || ids[1] != "si_3" {
+		t.Fatalf("work = %v, want [si_1 si_3] in conversation list order", ids)
+	}
+
+	// move si_3 from work to family
+	if err := db.DeleteFolderConversations(ctx, "work", []string{"si_3"}); err != nil {
+		t.Fatal(err)
+	}
+	if err := db.BatchInsertFolderConversations(ctx, []*model_struct.LocalFolderConversation{{FolderID: "family", ConversationID: "si_3"}}); err != nil {
+		t.Fatal(err)
+	}
+	if ids := folderConversationIDs(t, db, "work"); len(ids) != 1 || ids[0] != "si_1" {
+		t.Fatalf("work after move = %v, want [si_1]", ids)
+	}
+	if ids := folderConversationIDs(t, db, "family"); len(ids) != 2 || ids[0] != "si_1" || ids[1] != "si_3" {
+		t.Fatalf("family after move = %v, want [si_1 si_3]", ids)
+	}
+
+	// delete removes the member rows of the folder only, the conversations are kept
+	if err := db.DeleteConversationFolder(ctx, "family"); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := db.GetConversationFolder(ctx, "family"); err == nil {
+		t.Fatal("deleted folder is still found")
+	}
+	var memberCount int64
+	if err := db.conn.WithContext(ctx).Model(&model_struct.LocalFolderConversation{}).Where("folder_id = ?", "family").Count(&memberCount).Error; err != nil {
+		t.Fatal(err)
+	}
+	if memberCount != 0 {
+		t.Fatalf("%d member rows left after deleting the folder", memberCount)
+	}
+	if ids := folderConversationIDs(t, db, "work"); len(ids) != 1 || ids[0] != "si_1" {
+		t.Fatalf("work after deleting family = %v, want [si_1]", ids)
+	}
+	for _, conversation := range conversations {
+		if _, err := db.GetConversation(ctx, conversation.ConversationID); err != nil {
+			t.Fatalf("conversation %s: %v", conversation.ConversationID, err)
+		}
+	}
+}
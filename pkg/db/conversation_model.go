@@ -33,6 +33,8 @@ import (
 
 const (
 	batchSize = 200
+	// conversationListOrder pinned conversations first, then by the latest message or draft
+	conversationListOrder = "case when is_pinned=1 then 0 else 1 end,max(latest_msg_send_time,draft_text_time) DESC"
 )
 
 func (d *DataBase) GetConversationByUserID(ctx context.Context, userID string) (*model_struct.LocalConversation, error) {
@@ -47,7 +49,7 @@ func (d *DataBase) GetAllConversationListDB(ctx context.Context) ([]*model_struc
 	d.mRWMutex.RLock()
 	defer d.mRWMutex.RUnlock()
 	var conversationList []*model_struct.LocalConversation
-	err := errs.WrapMsg(d.conn.WithContext(ctx).Where("latest_msg_send_time > ?", 0).Order("case when is_pinned=1 then 0 else 1 end,max(latest_msg_send_time,draft_text_time) DESC").Find(&conversationList).Error,
+	err := errs.WrapMsg(d.conn.WithContext(ctx).Where("latest_msg_send_time > ?", 0).Order(conversationListOrder).Find(&conversationList).Error,
 		"GetAllConversationList failed")
 	if err != nil {
 		return nil, err
@@ -100,7 +102,7 @@ func (d *DataBase) GetConversationListSplitDB(ctx context.Context, offset, count
 	d.mRWMutex.RLock()
 	defer d.mRWMutex.RUnlock()
 	var conversationList []*model_struct.LocalConversation
-	return conversationList, errs.Wrap(d.conn.WithContext(ctx).Where("latest_msg_send_time > ?", 0).Order("case when is_pinned=1 then 0 else 1 end,max(latest_msg_send_time,draft_text_time) DESC").Offset(offset).Limit(count).Find(&conversationList).Error)
+	return conversationList, errs.Wrap(d.conn.WithContext(ctx).Where("latest_msg_send_time > ?", 0).Order(conversationListOrder).Offset(offset).Limit(count).Find(&conversationList).Error)
 }
 
 func (d *DataBase) BatchInsertConversationList(ctx context.Context, conversationList []*model_struct.LocalConversation) error {
@@ -238,8 +240,8 @@ func (d *DataBase) ConversationIfExists(ctx context.Context, conversationID stri
 func (d *DataBase) ResetConversation(ctx context.Context, conversationID string) error {
 	d.mRWMutex.Lock()
 	defer d.mRWMutex.Unlock()
-	c := model_struct.LocalConversation{ConversationID: conversationID, UnreadCount: 0, LatestMsg: "", LatestMsgSendTime: 0, DraftText: "", DraftTextTime: 0}
-	t := d.conn.WithContext(ctx).Select("unread_count", "latest_msg", "latest_msg_send_time", "draft_text", "draft_text_time").Updates(c)
+	c := model_struct.LocalConversation{ConversationID: conversationID, UnreadCount: 0, LatestMsg: "", LatestMsgSendTime: 0, DraftText: "", DraftTextTime: 0, StructuredDraft: ""}
+	t := d.conn.WithContext(ctx).Select("unread_count", "latest_msg", "latest_msg_send_time", "draft_text", "draft_text_time", "structured_draft").Updates(c)
 	if t.RowsAffected == 0 {
 		return errs.WrapMsg(errors.New("RowsAffected == 0"), "no update")
 	}
@@ -251,8 +253,8 @@ func (d *DataBase) ResetConversation(ctx context.Context, conversationID string)
 func (d *DataBase) ResetAllConversation(ctx context.Context) error {
 	d.mRWMutex.Lock()
 	defer d.mRWMutex.Unlock()
-	c := model_struct.LocalConversation{UnreadCount: 0, LatestMsg: "", LatestMsgSendTime: 0, DraftText: "", DraftTextTime: 0}
-	t := d.conn.WithContext(ctx).Session(&gorm.Session{AllowGlobalUpdate: true}).Select("unread_count", "latest_msg", "latest_msg_send_time", "draft_text", "draft_text_time").Updates(c)
+	c := model_struct.LocalConversation{UnreadCount: 0, LatestMsg: "", LatestMsgSendTime: 0, DraftText: "", DraftTextTime: 0, StructuredDraft: ""}
+	t := d.conn.WithContext(ctx).Session(&gorm.Session{AllowGlobalUpdate: true}).Select("unread_count", "latest_msg", "latest_msg_send_time", "draft_text", "draft_text_time", "structured_draft").Updates(c)
 	if t.RowsAffected == 0 {
 		return errs.WrapMsg(errors.New("RowsAffected == 0"), "no update")
 	}
@@ -265,20 +267,27 @@ func (d *DataBase) ResetAllConversation(ctx context.Context) error {
 func (d *DataBase) ClearConversation(ctx context.Context, conversationID string) error {
 	d.mRWMutex.Lock()
 	defer d.mRWMutex.Unlock()
-	c := model_struct.LocalConversation{ConversationID: conversationID, UnreadCount: 0, LatestMsg: "", DraftText: "", DraftTextTime: 0}
-	t := d.conn.WithContext(ctx).Select("unread_count", "latest_msg", "draft_text", "draft_text_time").Updates(c)
+	c := model_struct.LocalConversation{ConversationID: conversationID, UnreadCount: 0, LatestMsg: "", DraftText: "", DraftTextTime: 0, StructuredDraft: ""}
+	t := d.conn.WithContext(ctx).Select("unread_count", "latest_msg", "draft_text", "draft_text_time", "structured_draft").Updates(c)
 	if t.RowsAffected == 0 {
 		return errs.WrapMsg(errors.New("RowsAffected == 0"), "no update")
 	}
 	return errs.WrapMsg(t.Error, "ClearConversation failed")
 }
 
+// SetConversationDraftDB sets a plain text draft, a structured draft set before is dropped.
 func (d *DataBase) SetConversationDraftDB(ctx context.Context, conversationID, draftText string) error {
+	return d.SetConversationStructuredDraftDB(ctx, conversationID, draftText, "")
+}
+
+// SetConversationStructuredDraftDB sets draft_text to the plain text of the draft and structured_draft to its json,
+// so that readers of draft_text keep seeing plain text.
+func (d *DataBase) SetConversationStructuredDraftDB(ctx context.Context, conversationID, draftText, structuredDraft string) error {
 	d.mRWMutex.Lock()
 	defer d.mRWMutex.Unlock()
 	nowTime := utils.GetCurrentTimestampByMill()
-	t := d.conn.WithContext(ctx).Exec("update local_conversations set draft_text=?,draft_text_time=?,latest_msg_send_time=case when latest_msg_send_time=? then ? else latest_msg_send_time  end where conversation_id=?",
-		draftText, nowTime, 0, nowTime, conversationID)
+	t := d.conn.WithContext(ctx).Exec("update local_conversations set draft_text=?,structured_draft=?,draft_text_time=?,latest_msg_send_time=case when latest_msg_send_time=? then ? else latest_msg_send_time  end where conversation_id=?",
+		draftText, structuredDraft, nowTime, 0, nowTime, conversationID)
 	if t.RowsAffected == 0 {
 		return errs.WrapMsg(errors.New("RowsAffected == 0"), "no update")
 	}
@@ -288,8 +297,8 @@ func (d *DataBase) SetConversationDraftDB(ctx context.Context, conversationID, d
 func (d *DataBase) RemoveConversationDraft(ctx context.Context, conversationID, draftText string) error {
 	d.mRWMutex.Lock()
 	defer d.mRWMutex.Unlock()
-	c := model_struct.LocalConversation{ConversationID: conversationID, DraftText: draftText, DraftTextTime: 0}
-	t := d.conn.WithContext(ctx).Select("draft_text", "draft_text_time").Updates(c)
+	c := model_struct.LocalConversation{ConversationID: conversationID, DraftText: draftText, DraftTextTime: 0, StructuredDraft: ""}
+	t := d.conn.WithContext(ctx).Select("draft_text", "draft_text_time", "structured_draft").Updates(c)
 	if t.RowsAffected == 0 {
 		return errs.WrapMsg(errors.New("RowsAffected == 0"), "no update")
 	}
@@ -152,7 +152,13 @@ func (d *DataBase) initDB(ctx context.Context, logLevel int) error {
 	d.conn = db
 
 	// base
-	if err = db.AutoMigrate(&model_struct.LocalAppSDKVersion{}); err != nil {
+	if err = db.AutoMigrate(
+		&model_struct.LocalAppSDKVersion{},
+		&model_struct.LocalFavorite{},
+		&model_struct.LocalStarredMessage{},
+		&model_struct.LocalConversationFolder{},
+		&model_struct.LocalFolderConversation{},
+	); err != nil {
 		return err
 	}
 
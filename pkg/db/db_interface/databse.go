@@ -62,6 +62,7 @@ type MessageModel interface {
 	MarkConversationMessageAsReadDB(ctx context.Context, conversationID string, msgIDs []string) (rowsAffected int64, err error)
 	MarkConversationMessageAsReadBySeqs(ctx context.Context, conversationID string, seqs []int64) (rowsAffected int64, err error)
 	GetUnreadMessage(ctx context.Context, conversationID string) (result []*model_struct.LocalChatLog, err error)
+	GetBurnedPrivateChatMessages(ctx context.Context, conversationID string, now int64) (result []*model_struct.LocalChatLog, err error)
 	MarkConversationAllMessageAsRead(ctx context.Context, conversationID string) (rowsAffected int64, err error)
 	GetMessagesByClientMsgIDs(ctx context.Context, conversationID string, msgIDs []string) (result []*model_struct.LocalChatLog, err error)
 	GetMessagesBySeqs(ctx context.Context, conversationID string, seqs []int64) (result []*model_struct.LocalChatLog, err error)
@@ -106,6 +107,7 @@ type ConversationModel interface {
 	ResetAllConversation(ctx context.Context) error
 	ClearConversation(ctx context.Context, conversationID string) error
 	SetConversationDraftDB(ctx context.Context, conversationID, draftText string) error
+	SetConversationStructuredDraftDB(ctx context.Context, conversationID, draftText, structuredDraft string) error
 	RemoveConversationDraft(ctx context.Context, conversationID, draftText string) error
 	UnPinConversation(ctx context.Context, conversationID string, isPinned int) error
 	UpdateColumnsConversation(ctx context.Context, conversationID string, args map[string]interface{}) error
@@ -163,6 +165,28 @@ type SendingMessagesModel interface {
 	DeleteSendingMessage(ctx context.Context, conversationID, clientMsgID string) error
 	GetAllSendingMessages(ctx context.Context) (friendRequests []*model_struct.LocalSendingMessages, err error)
 }
+type FavoriteModel interface {
+	InsertFavorite(ctx context.Context, favorite *model_struct.LocalFavorite) error
+	DeleteFavorite(ctx context.Context, conversationID, clientMsgID string) error
+	GetFavoriteList(ctx context.Context, offset, count int) ([]*model_struct.LocalFavorite, error)
+}
+type StarredMessageModel interface {
+	InsertStarredMessage(ctx context.Context, message *model_struct.LocalStarredMessage) error
+	DeleteStarredMessage(ctx context.Context, conversationID, clientMsgID string) error
+	DeleteStarredMessages(ctx context.Context, conversationID string, clientMsgIDs []string) error
+	DeleteConversationStarredMessages(ctx context.Context, conversationID string) error
+	GetStarredMessageList(ctx context.Context, conversationID string) ([]*model_struct.LocalStarredMessage, error)
+}
+type ConversationFolderModel interface {
+	InsertConversationFolder(ctx context.Context, folder *model_struct.LocalConversationFolder) error
+	UpdateConversationFolder(ctx context.Context, folder *model_struct.LocalConversationFolder) error
+	DeleteConversationFolder(ctx context.Context, folderID string) error
+	GetConversationFolder(ctx context.Context, folderID string) (*model_struct.LocalConversationFolder, error)
+	GetAllConversationFolders(ctx context.Context) ([]*model_struct.LocalConversationFolder, error)
+	BatchInsertFolderConversations(ctx context.Context, folderConversations []*model_struct.LocalFolderConversation) error
+	DeleteFolderConversations(ctx context.Context, folderID string, conversationIDs []string) error
+	GetConversationListByFolderDB(ctx context.Context, folderID string, offset, count int) ([]*model_struct.LocalConversation, error)
+}
 
 type VersionSyncModel interface {
 	GetVersionSync(ctx context.Context, tableName, entityID string) (*model_struct.LocalVersionSync, error)
@@ -187,6 +211,9 @@ type DataBase interface {
 	FriendModel
 	S3Model
 	SendingMessagesModel
+	FavoriteModel
+	StarredMessageModel
+	ConversationFolderModel
 	VersionSyncModel
 	AppSDKVersion
 	TableMaster
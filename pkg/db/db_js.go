@@ -36,6 +36,9 @@ type IndexDB struct {
 	*indexdb.NotificationSeqs
 	*indexdb.LocalUpload
 	*indexdb.LocalSendingMessages
+	*indexdb.LocalFavorites
+	*indexdb.LocalStarredMessages
+	*indexdb.LocalConversationFolders
 	*indexdb.LocalVersionSync
 	*indexdb.LocalAppSDKVersion
 	*indexdb.LocalTableMaster
@@ -65,6 +68,9 @@ func NewDataBase(ctx context.Context, loginUserID string, dbDir string, logLevel
 		NotificationSeqs:                indexdb.NewNotificationSeqs(),
 		LocalUpload:                     indexdb.NewLocalUpload(),
 		LocalSendingMessages:            indexdb.NewLocalSendingMessages(),
+		LocalFavorites:                  indexdb.NewLocalFavorites(),
+		LocalStarredMessages:            indexdb.NewLocalStarredMessages(),
+		LocalConversationFolders:        indexdb.NewLocalConversationFolders(),
 		LocalVersionSync:                indexdb.NewLocalVersionSync(),
 		LocalAppSDKVersion:              indexdb.NewLocalAppSDKVersion(),
 		LocalTableMaster:                indexdb.NewLocalTableMaster(),
@@ -205,6 +205,7 @@ type LocalConversation struct {
 	LatestMsgSendTime     int64  `gorm:"column:latest_msg_send_time;index:index_latest_msg_send_time" json:"latestMsgSendTime"`
 	DraftText             string `gorm:"column:draft_text" json:"draftText"`
 	DraftTextTime         int64  `gorm:"column:draft_text_time" json:"draftTextTime"`
+	StructuredDraft       string `gorm:"column:structured_draft" json:"structuredDraft"`
 	IsPinned              bool   `gorm:"column:is_pinned" json:"isPinned"`
 	IsPrivateChat         bool   `gorm:"column:is_private_chat" json:"isPrivateChat"`
 	BurnDuration          int32  `gorm:"column:burn_duration;default:30" json:"burnDuration"`
@@ -216,6 +217,7 @@ type LocalConversation struct {
 	MinSeq                int64  `gorm:"column:min_seq" json:"minSeq"`
 	MsgDestructTime       int64  `gorm:"column:msg_destruct_time;default:604800" json:"msgDestructTime"`
 	IsMsgDestruct         bool   `gorm:"column:is_msg_destruct;default:false" json:"isMsgDestruct"`
+	LatestMsgDestructTime int64  `gorm:"column:latest_msg_destruct_time" json:"latestMsgDestructTime"`
 }
 
 func (LocalConversation) TableName() string {
@@ -284,6 +286,48 @@ func (LocalSendingMessages) TableName() string {
 	return "local_sending_messages"
 }
 
+// LocalFavorite keeps a snapshot of a collected message, so it outlives the message in its conversation.
+type LocalFavorite struct {
+	ConversationID string `gorm:"column:conversation_id;primary_key;type:char(128)" json:"conversationID"`
+	ClientMsgID    string `gorm:"column:client_msg_id;primary_key;type:char(64)" json:"clientMsgID"`
+	Message        string `gorm:"column:message;type:text" json:"message"`
+	CreateTime     int64  `gorm:"column:create_time;index:index_create_time" json:"createTime"`
+}
+
+func (LocalFavorite) TableName() string {
+	return "local_favorites"
+}
+
+// LocalStarredMessage marks a message as starred. It is kept apart from the chat log so syncing the message never clears it.
+type LocalStarredMessage struct {
+	ConversationID string `gorm:"column:conversation_id;primary_key;type:char(128)" json:"conversationID"`
+	ClientMsgID    string `gorm:"column:client_msg_id;primary_key;type:char(64)" json:"clientMsgID"`
+	CreateTime     int64  `gorm:"column:create_time" json:"createTime"`
+}
+
+func (LocalStarredMessage) TableName() string {
+	return "local_starred_messages"
+}
+
+type LocalConversationFolder struct {
+	FolderID   string `gorm:"column:folder_id;primary_key;type:char(64)" json:"folderID"`
+	Name       string `gorm:"column:name;type:varchar(255)" json:"name"`
+	CreateTime int64  `gorm:"column:create_time" json:"createTime"`
+}
+
+func (LocalConversationFolder) TableName() string {
+	return "local_conversation_folders"
+}
+
+type LocalFolderConversation struct {
+	FolderID       string `gorm:"column:folder_id;primary_key;type:char(64)" json:"folderID"`
+	ConversationID string `gorm:"column:conversation_id;primary_key;type:char(128)" json:"conversationID"`
+}
+
+func (LocalFolderConversation) TableName() string {
+	return "local_folder_conversations"
+}
+
 type StringArray []string
 
 func (a StringArray) Value() (driver.Value, error) {
@@ -0,0 +1,60 @@
+// Copyright © 2023 OpenIM SDK. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//go:build !js
+// +build !js
+
+package db
+
+import (
+	"context"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/tools/errs"
+)
+
+func (d *DataBase) InsertStarredMessage(ctx context.Context, message *model_struct.LocalStarredMessage) error {
+	d.mRWMutex.Lock()
+	defer d.mRWMutex.Unlock()
+	return errs.WrapMsg(d.conn.WithContext(ctx).Create(message).Error, "InsertStarredMessage failed")
+}
+
+func (d *DataBase) DeleteStarredMessage(ctx context.Context, conversationID, clientMsgID string) error {
+	d.mRWMutex.Lock()
+	defer d.mRWMutex.Unlock()
+	message := model_struct.LocalStarredMessage{ConversationID: conversationID, ClientMsgID: clientMsgID}
+	return errs.WrapMsg(d.conn.WithContext(ctx).Delete(&message).Error, "DeleteStarredMessage failed")
+}
+
+func (d *DataBase) DeleteStarredMessages(ctx context.Context, conversationID string, clientMsgIDs []string) error {
+	if len(clientMsgIDs) == 0 {
+		return nil
+	}
+	d.mRWMutex.Lock()
+	defer d.mRWMutex.Unlock()
+	return errs.WrapMsg(d.conn.WithContext(ctx).Where("conversation_id = ? and client_msg_id in ?", conversationID, clientMsgIDs).Delete(&model_struct.LocalStarredMessage{}).Error, "DeleteStarredMessages failed")
+}
+
+func (d *DataBase) DeleteConversationStarredMessages(ctx context.Context, conversationID string) error {
+	d.mRWMutex.Lock()
+	defer d.mRWMutex.Unlock()
+	return errs.WrapMsg(d.conn.WithContext(ctx).Where("conversation_id = ?", conversationID).Delete(&model_struct.LocalStarredMessage{}).Error, "DeleteConversationStarredMessages failed")
+}
+
+func (d *DataBase) GetStarredMessageList(ctx context.Context, conversationID string) ([]*model_struct.LocalStarredMessage, error) {
+	d.mRWMutex.RLock()
+	defer d.mRWMutex.RUnlock()
+	var messages []*model_struct.LocalStarredMessage
+	return messages, errs.WrapMsg(d.conn.WithContext(ctx).Where("conversation_id = ?", conversationID).Order("create_time DESC").Find(&messages).Error, "GetStarredMessageList failed")
+}
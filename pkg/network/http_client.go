@@ -34,9 +34,11 @@ import (
 	"github.com/openimsdk/tools/log"
 )
 
-// apiClient is a global HTTP client with a timeout of one minute.
+const apiTimeout = time.Second * 10
+
+// apiClient is the global HTTP client for api requests, replaced by SetTLSConfig and read with getApiClient.
 var apiClient = &http.Client{
-	Timeout: time.Second * 10,
+	Timeout: apiTimeout,
 }
 
 // ApiResponse represents the standard structure of an API response.
@@ -100,8 +102,8 @@ func ApiPost(ctx context.Context, api string, req, resp any) (err error) {
 	request.Header.Set("token", ctxInfo.Token())
 	request.Header.Set("Accept-Encoding", "gzip")
 
-	// Send the request and receive the response.
-	response, err := apiClient.Do(request)
+	// Send the request and receive the response, retrying transient failures.
+	response, err := doWithRetry(ctx, request)
 	if err != nil {
 		log.ZError(ctx, "ApiRequest", err, "type", "network error")
 		return sdkerrs.ErrNetwork.WrapMsg("ApiPost http.Client.Do failed " + err.Error())
@@ -0,0 +1,138 @@
+// Copyright © 2023 OpenIM SDK. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+package network
+
+import (
+	"context"
+	"errors"
+	"net"
+	"net/http"
+	"path"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/openimsdk/tools/log"
+)
+
+// RetryPolicy controls how ApiPost retries requests that failed transiently.
+type RetryPolicy struct {
+	// Attempts total number of attempts, 1 disables retrying
+	Attempts int
+	// Backoff wait before the first retry, doubled for every further retry
+	Backoff time.Duration
+	// MaxBackoff upper bound of the wait between two attempts
+	MaxBackoff time.Duration
+}
+
+var (
+	retryLock   sync.RWMutex
+	retryPolicy = DefaultRetryPolicy()
+)
+
+// DefaultRetryPolicy is the policy used when the sdk config sets none.
+func DefaultRetryPolicy() RetryPolicy {
+	return RetryPolicy{
+		Attempts:   3,
+		Backoff:    time.Millisecond * 500,
+		MaxBackoff: time.Second * 4,
+	}
+}
+
+// SetRetryPolicy replaces the retry policy used by ApiPost.
+func SetRetryPolicy(policy RetryPolicy) {
+	if policy.Attempts < 1 {
+		policy.Attempts = 1
+	}
+	retryLock.Lock()
+	defer retryLock.Unlock()
+	retryPolicy = policy
+}
+
+func getRetryPolicy() RetryPolicy {
+	retryLock.RLock()
+	defer retryLock.RUnlock()
+	return retryPolicy
+}
+
+func (p RetryPolicy) backoff(attempt int) time.Duration {
+	wait := p.Backoff
+	for i := 1; i < attempt; i++ {
+		wait *= 2
+		if p.MaxBackoff > 0 && wait >= p.MaxBackoff {
+			return p.MaxBackoff
+		}
+	}
+	if p.MaxBackoff > 0 && wait > p.MaxBackoff {
+		return p.MaxBackoff
+	}
+	return wait
+}
+
+// isIdempotent reports whether repeating a call to the api route cannot change server state.
+// Only the read routes, named get_*, qualify.
+func isIdempotent(api string) bool {
+	return strings.HasPrefix(path.Base(api), "get_")
+}
+
+// isNotSent reports whether err happened before the request reached the server,
+// the connection could not be established.
+func isNotSent(err error) bool {
+	var opErr *net.OpError
+	return errors.As(err, &opErr) && opErr.Op == "dial"
+}
+
+// isRetryable reports whether a failed attempt is worth repeating. Requests that never reached the
+// server are always retried. Timeouts, other transport errors and 5xx statuses except 501 may come
+// after the server acted on the request, so they are only retried for idempotent routes.
+func isRetryable(ctx context.Context, api string, response *http.Response, err error) bool {
+	if ctx.Err() != nil {
+		return false
+	}
+	if err != nil {
+		return isNotSent(err) || isIdempotent(api)
+	}
+	return isIdempotent(api) && response.StatusCode >= http.StatusInternalServerError && response.StatusCode != http.StatusNotImplemented
+}
+
+// doWithRetry sends the request, retrying with backoff according to the retry policy.
+func doWithRetry(ctx context.Context, request *http.Request) (*http.Response, error) {
+	policy := getRetryPolicy()
+	for attempt := 1; ; attempt++ {
+		response, err := getApiClient().Do(request)
+		if attempt >= policy.Attempts || !isRetryable(ctx, request.URL.Path, response, err) {
+			return response, err
+		}
+		if err == nil {
+			log.ZWarn(ctx, "ApiRequest retry", nil, "attempt", attempt, "url", request.URL.String(), "status", response.Status)
+			response.Body.Close()
+		} else {
+			log.ZWarn(ctx, "ApiRequest retry", err, "attempt", attempt, "url", request.URL.String())
+		}
+		timer := time.NewTimer(policy.backoff(attempt))
+		select {
+		case <-ctx.Done():
+			timer.Stop()
+			return nil, context.Cause(ctx)
+		case <-timer.C:
+		}
+		body, err := request.GetBody()
+		if err != nil {
+			return nil, err
+		}
+		request = request.Clone(ctx)
+		request.Body = body
+	}
+}
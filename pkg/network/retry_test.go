@@ -0,0 +1,102 @@
+package network
+
+import (
+	"context"
+	"errors"
+	"net"
+	"net/http"
+	"net/http/httptest"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/ccontext"
+)
+
+func TestApiPostRetry(t *testing.T) {
+	SetRetryPolicy(RetryPolicy{Attempts: 3, Backoff: time.Millisecond})
+	defer SetRetryPolicy(DefaultRetryPolicy())
+
+	var calls atomic.Int32
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.Header.Get("operationID") != "retry" {
+			t.Errorf("operationID %q", r.Header.Get("operationID"))
+		}
+		if calls.Add(1) < 3 {
+			w.WriteHeader(http.StatusServiceUnavailable)
+			return
+		}
+		w.Write([]byte(`{"errCode":0,"data":{"ok":true}}`))
+	}))
+	defer server.Close()
+
+	var conf ccontext.GlobalConfig
+	conf.ApiAddr = server.URL
+	ctx := ccontext.WithInfo(context.Background(), &conf)
+	ctx = ccontext.WithOperationID(ctx, "retry")
+	var resp struct {
+		Ok bool `json:"ok"`
+	}
+	if err := ApiPost(ctx, "/test/get_test", map[string]any{}, &resp); err != nil {
+		t.Fatal(err)
+	}
+	if !resp.Ok || calls.Load() != 3 {
+		t.Fatalf("resp %v calls %d", resp.Ok, calls.Load())
+	}
+}
+
+func TestApiPostNoRetryForWrites(t *testing.T) {
+	SetRetryPolicy(RetryPolicy{Attempts: 3, Backoff: time.Millisecond})
+	defer SetRetryPolicy(DefaultRetryPolicy())
+
+	var calls atomic.Int32
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		calls.Add(1)
+		w.WriteHeader(http.StatusServiceUnavailable)
+	}))
+	defer server.Close()
+
+	var conf ccontext.GlobalConfig
+	conf.ApiAddr = server.URL
+	ctx := ccontext.WithInfo(context.Background(), &conf)
+	ctx = ccontext.WithOperationID(ctx, "no-retry")
+	if err := ApiPost(ctx, "/msg/send_msg", map[string]any{}, nil); err == nil {
+		t.Fatal("expected an error")
+	}
+	if calls.Load() != 1 {
+		t.Fatalf("send_msg sent %d times, want 1", calls.Load())
+	}
+}
+
+func TestIsRetryable(t *testing.T) {
+	ctx := context.Background()
+	dialErr := &net.OpError{Op: "dial", Net: "tcp", Err: errors.New("connection refused")}
+	readErr := &net.OpError{Op: "read", Net: "tcp", Err: errors.New("i/o timeout")}
+	unavailable := &http.Response{StatusCode: http.StatusServiceUnavailable}
+	for _, c := range []struct {
+		api      string
+		response *http.Response
+		err      error
+		want     bool
+	}{
+		{"/msg/send_msg", nil, dialErr, true},
+		{"/msg/send_msg", nil, readErr, false},
+		{"/msg/send_msg", unavailable, nil, false},
+		{"/friend/get_incremental_friends", nil, readErr, true},
+		{"/friend/get_incremental_friends", unavailable, nil, true},
+		{"/friend/get_incremental_friends", &http.Response{StatusCode: http.StatusNotImplemented}, nil, false},
+	} {
+		if got := isRetryable(ctx, c.api, c.response, c.err); got != c.want {
+			t.Errorf("isRetryable(%s, %v, %v) = %v, want %v", c.api, c.response, c.err, got, c.want)
+		}
+	}
+}
+
+func TestRetryPolicyBackoff(t *testing.T) {
+	p := RetryPolicy{Backoff: time.Second, MaxBackoff: time.Second * 3}
+	for attempt, want := range []time.Duration{time.Second, time.Second * 2, time.Second * 3, time.Second * 3} {
+		if got := p.backoff(attempt + 1); got != want {
+			t.Errorf("attempt %d backoff %s, want %s", attempt+1, got, want)
+		}
+	}
+}
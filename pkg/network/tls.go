@@ -0,0 +1,96 @@
+// Copyright © 2023 OpenIM SDK. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+package network
+
+import (
+	"crypto/sha256"
+	"crypto/tls"
+	"crypto/x509"
+	"encoding/hex"
+	"errors"
+	"net/http"
+	"strings"
+	"sync"
+
+	"github.com/openimsdk/openim-sdk-core/v3/sdk_struct"
+	"github.com/openimsdk/tools/errs"
+)
+
+var (
+	ErrCertificateNotPinned = errors.New("server certificate does not match any pinned fingerprint")
+
+	// tlsLock guards tlsConfig and apiClient, InitSDK may replace them while requests are running.
+	tlsLock   sync.RWMutex
+	tlsConfig *tls.Config
+)
+
+// NewTLSConfig builds a client TLS configuration from the sdk config.
+func NewTLSConfig(conf *sdk_struct.TLSConfig) (*tls.Config, error) {
+	tlsConf := &tls.Config{InsecureSkipVerify: conf.InsecureSkipVerify}
+	if conf.RootCAs != "" {
+		pool, err := x509.SystemCertPool()
+		if err != nil || pool == nil {
+			pool = x509.NewCertPool()
+		}
+		if !pool.AppendCertsFromPEM([]byte(conf.RootCAs)) {
+			return nil, errs.New("rootCAs contains no valid PEM certificate").Wrap()
+		}
+		tlsConf.RootCAs = pool
+	}
+	if len(conf.PinnedSHA256) > 0 {
+		pins := make(map[string]struct{}, len(conf.PinnedSHA256))
+		for _, pin := range conf.PinnedSHA256 {
+			pins[strings.ToLower(strings.ReplaceAll(pin, ":", ""))] = struct{}{}
+		}
+		tlsConf.VerifyPeerCertificate = func(rawCerts [][]byte, _ [][]*x509.Certificate) error {
+			for _, raw := range rawCerts {
+				sum := sha256.Sum256(raw)
+				if _, ok := pins[hex.EncodeToString(sum[:])]; ok {
+					return nil
+				}
+			}
+			return ErrCertificateNotPinned
+		}
+	}
+	return tlsConf, nil
+}
+
+// SetTLSConfig applies conf to api requests and to the gateway dialer, nil restores the system default.
+// Object storage uploads keep the default client because they target other hosts.
+func SetTLSConfig(conf *tls.Config) {
+	client := &http.Client{Timeout: apiTimeout}
+	if conf != nil {
+		transport := http.DefaultTransport.(*http.Transport).Clone()
+		transport.TLSClientConfig = conf
+		client.Transport = transport
+	}
+	tlsLock.Lock()
+	defer tlsLock.Unlock()
+	tlsConfig = conf
+	apiClient = client
+}
+
+// TLSConfig returns the configuration set by SetTLSConfig, nil means the system default.
+func TLSConfig() *tls.Config {
+	tlsLock.RLock()
+	defer tlsLock.RUnlock()
+	return tlsConfig
+}
+
+func getApiClient() *http.Client {
+	tlsLock.RLock()
+	defer tlsLock.RUnlock()
+	return apiClient
+}
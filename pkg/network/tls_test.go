@@ -0,0 +1,58 @@
+package network
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/pem"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+
+	"github.com/openimsdk/openim-sdk-core/v3/sdk_struct"
+)
+
+func TestTLSConfigPinning(t *testing.T) {
+	server := httptest.NewTLSServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {}))
+	defer server.Close()
+	cert := server.Certificate()
+	rootCAs := string(pem.EncodeToMemory(&pem.Block{Type: "CERTIFICATE", Bytes: cert.Raw}))
+	sum := sha256.Sum256(cert.Raw)
+
+	get := func(conf *sdk_struct.TLSConfig) error {
+		tlsConf, err := NewTLSConfig(conf)
+		if err != nil {
+			return err
+		}
+		client := &http.Client{Transport: &http.Transport{TLSClientConfig: tlsConf}}
+		resp, err := client.Get(server.URL)
+		if err != nil {
+			return err
+		}
+		return resp.Body.Close()
+	}
+
+	if err := get(&sdk_struct.TLSConfig{RootCAs: rootCAs, PinnedSHA256: []string{hex.EncodeToString(sum[:])}}); err != nil {
+		t.Fatalf("pinned certificate rejected: %v", err)
+	}
+	if err := get(&sdk_struct.TLSConfig{RootCAs: rootCAs, PinnedSHA256: []string{hex.EncodeToString(make([]byte, sha256.Size))}}); err == nil {
+		t.Fatal("unpinned certificate accepted")
+	}
+	if _, err := NewTLSConfig(&sdk_struct.TLSConfig{RootCAs: "not a certificate"}); err == nil {
+		t.Fatal("invalid rootCAs accepted")
+	}
+}
+
+func TestSetTLSConfigReset(t *testing.T) {
+	tlsConf, err := NewTLSConfig(&sdk_struct.TLSConfig{InsecureSkipVerify: true})
+	if err != nil {
+		t.Fatal(err)
+	}
+	SetTLSConfig(tlsConf)
+	if TLSConfig() != tlsConf || getApiClient().Transport == nil {
+		t.Fatal("tls config not applied")
+	}
+	SetTLSConfig(nil)
+	if TLSConfig() != nil || getApiClient().Transport != nil {
+		t.Fatal("tls config kept after reset")
+	}
+}
@@ -78,3 +78,23 @@ type SearchByConversationResult struct {
 	MessageCount      int                     `json:"messageCount"`
 	MessageList       []*sdk_struct.MsgStruct `json:"messageList"`
 }
+
+type FavoriteMessage struct {
+	ConversationID string                `json:"conversationID"`
+	CreateTime     int64                 `json:"createTime"`
+	Message        *sdk_struct.MsgStruct `json:"message"`
+}
+
+// StructuredDraft is the composer state of a conversation, kept as json in structured_draft while draft_text holds its Text.
+// Attachments are messages created but not sent yet, e.g. by CreateImageMessageFromFullPath.
+type StructuredDraft struct {
+	Text             string                  `json:"text"`
+	Attachments      []*sdk_struct.MsgStruct `json:"attachments,omitempty"`
+	QuoteClientMsgID string                  `json:"quoteClientMsgID,omitempty"`
+	Ex               string                  `json:"ex,omitempty"`
+}
+
+type ConversationRecvMsgOpt struct {
+	ConversationID string `json:"conversationID"`
+	RecvMsgOpt     int32  `json:"recvMsgOpt"`
+}
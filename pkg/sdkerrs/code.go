@@ -41,6 +41,8 @@ const (
 	MsgContentTypeNotSupportError = 10205 // Message content type not supported
 	MsgHasNoSeqError              = 10206 // Message does not have a sequence number
 	MsgHasDeletedError            = 10207 // Message has been deleted
+	MsgContentTooLargeError       = 10208 // Message content exceeds the configured size
+	MsgThrottledError             = 10209 // Message send rate limit exceeded
 
 	// Conversation-related errors
 	NotSupportOptError  = 10301 // Operation not supported
@@ -38,6 +38,8 @@ var (
 	ErrMsgContentTypeNotSupport = errs.NewCodeError(MsgContentTypeNotSupportError, "Message content type not supported")
 	ErrMsgHasNoSeq              = errs.NewCodeError(MsgHasNoSeqError, "Message has no sequence number")
 	ErrMsgHasDeleted            = errs.NewCodeError(MsgHasDeletedError, "Message has been deleted")
+	ErrMsgContentTooLarge       = errs.NewCodeError(MsgContentTooLargeError, "Message content is too large")
+	ErrMsgThrottled             = errs.NewCodeError(MsgThrottledError, "Message send rate limit exceeded")
 
 	// Conversation-related errors
 	ErrNotSupportOpt  = errs.NewCodeError(NotSupportOptError, "Operation not supported for supergroup")
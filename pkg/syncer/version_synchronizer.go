@@ -18,6 +18,7 @@ type VersionSynchronizer[V, R any] struct {
 	EntityID           string
 	Key                func(V) string
 	Local              func() ([]V, error)
+	LocalByIDs         func(ids []string) ([]V, error)
 	ServerVersion      func() R
 	Server             func(version *model_struct.LocalVersionSync) (R, error)
 	Full               func(resp R) bool
@@ -33,6 +34,19 @@ type VersionSynchronizer[V, R any] struct {
 	IDOrderChanged     func(resp R) bool
 }
 
+// local loads the local rows an incremental response is diffed against.
+// With LocalByIDs only the rows touched by the response are read instead of the whole table.
+func (o *VersionSynchronizer[V, R]) local(changes []V, delIDs []string) ([]V, error) {
+	if o.LocalByIDs == nil {
+		return o.Local()
+	}
+	ids := append(datautil.Slice(changes, o.Key), delIDs...)
+	if len(ids) == 0 {
+		return nil, nil
+	}
+	return o.LocalByIDs(ids)
+}
+
 func (o *VersionSynchronizer[V, R]) getVersionInfo() (*model_struct.LocalVersionSync, error) {
 	versionInfo, err := o.DB.GetVersionSync(o.Ctx, o.TableName, o.EntityID)
 	if err != nil && errs.Unwrap(err) != errs.ErrRecordNotFound {
@@ -116,7 +130,7 @@ func (o *VersionSynchronizer[V, R]) IncrementalSync() error {
 			}
 		}
 
-		local, err := o.Local()
+		local, err := o.local(changes, delIDs)
 		if err != nil {
 			return err
 		}
@@ -199,7 +213,7 @@ func (o *VersionSynchronizer[V, R]) CheckVersionSync() error {
 			changes = append(changes, insert...)
 		}
 
-		local, err := o.Local()
+		local, err := o.local(changes, delIDs)
 		if err != nil {
 			return err
 		}
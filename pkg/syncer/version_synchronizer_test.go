@@ -0,0 +1,136 @@
+package syncer
+
+import (
+	"context"
+	"sort"
+	"testing"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+)
+
+type testItem struct {
+	ID   string
+	Name string
+}
+
+type testIncrResp struct {
+	Version uint64
+	Delete  []string
+	Update  []*testItem
+	Insert  []*testItem
+}
+
+type testVersionDB struct {
+	lvs *model_struct.LocalVersionSync
+}
+
+func (d *testVersionDB) GetVersionSync(ctx context.Context, tableName, entityID string) (*model_struct.LocalVersionSync, error) {
+	lvs := *d.lvs
+	return &lvs, nil
+}
+
+func (d *testVersionDB) SetVersionSync(ctx context.Context, version *model_struct.LocalVersionSync) error {
+	d.lvs = version
+	return nil
+}
+
+func (d *testVersionDB) DeleteVersionSync(ctx context.Context, tableName, entityID string) error {
+	return nil
+}
+
+func sortedIDs(items []*testItem) []string {
+	ids := make([]string, 0, len(items))
+	for _, item := range items {
+		ids = append(ids, item.ID)
+	}
+	sort.Strings(ids)
+	return ids
+}
+
+func equalIDs(a, b []string) bool {
+	if len(a) != len(b) {
+		return false
+	}
+	for i := range a {
+		if a[i] != b[i] {
+			return false
+		}
+	}
+	return true
+}
+
+func TestVersionSynchronizerReadsTouchedIDsOnly(t *testing.T) {
+	local := map[string]*testItem{}
+	for _, id := range []string{"a", "b", "c", "d", "e"} {
+		local[id] = &testItem{ID: id, Name: id}
+	}
+	resp := &testIncrResp{
+		Version: 2,
+		Delete:  []string{"b"},
+		Update:  []*testItem{{ID: "c", Name: "c2"}},
+		Insert:  []*testItem{{ID: "f", Name: "f"}},
+	}
+
+	for _, tt := range []struct {
+		name string
+		sync func(o *VersionSynchronizer[*testItem, *testIncrResp]) error
+	}{
+		{"incremental", func(o *VersionSynchronizer[*testItem, *testIncrResp]) error { return o.IncrementalSync() }},
+		{"notification", func(o *VersionSynchronizer[*testItem, *testIncrResp]) error {
+			o.ServerVersion = func() *testIncrResp { return resp }
+			return o.CheckVersionSync()
+		}},
+	} {
+		var readIDs []string
+		var server, synced []*testItem
+		o := &VersionSynchronizer[*testItem, *testIncrResp]{
+			Ctx:       context.Background(),
+			DB:        &testVersionDB{lvs: &model_struct.LocalVersionSync{VersionID: "v", Version: 1, UIDList: []string{"a", "b", "c", "d", "e"}}},
+			TableName: "test",
+			EntityID:  "test_user",
+			Key:       func(item *testItem) string { return item.ID },
+			Local: func() ([]*testItem, error) {
+				t.Errorf("%s: the whole local table was read", tt.name)
+				return nil, nil
+			},
+			LocalByIDs: func(ids []string) ([]*testItem, error) {
+				readIDs = append(readIDs, ids...)
+				var res []*testItem
+				for _, id := range ids {
+					if item, ok := local[id]; ok {
+						res = append(res, item)
+					}
+				}
+				return res, nil
+			},
+			Server:  func(version *model_struct.LocalVersionSync) (*testIncrResp, error) { return resp, nil },
+			Full:    func(resp *testIncrResp) bool { return false },
+			Version: func(resp *testIncrResp) (string, uint64) { return "v", resp.Version },
+			Delete:  func(resp *testIncrResp) []string { return resp.Delete },
+			Update:  func(resp *testIncrResp) []*testItem { return resp.Update },
+			Insert:  func(resp *testIncrResp) []*testItem { return resp.Insert },
+			Syncer: func(s, l []*testItem) error {
+				server, synced = s, l
+				return nil
+			},
+		}
+		if err := tt.sync(o); err != nil {
+			t.Fatalf("%s: %v", tt.name, err)
+		}
+		sort.Strings(readIDs)
+		if !equalIDs(readIDs, []string{"b", "c", "f"}) {
+			t.Errorf("%s: read local IDs %v, want only the touched [b c f]", tt.name, readIDs)
+		}
+		if ids := sortedIDs(synced); !equalIDs(ids, []string{"b", "c"}) {
+			t.Errorf("%s: local rows diffed %v, want [b c]", tt.name, ids)
+		}
+		if ids := sortedIDs(server); !equalIDs(ids, []string{"c", "f"}) {
+			t.Errorf("%s: server rows %v, want [c f]", tt.name, ids)
+		}
+		for _, item := range server {
+			if item.ID == "c" && item.Name != "c2" {
+				t.Errorf("%s: updated row = %+v, want the server version", tt.name, item)
+			}
+		}
+	}
+}
@@ -0,0 +1,33 @@
+package sdk_struct
+
+import "encoding/json"
+
+// ParseMessageEntities decodes a messageEntityList JSON string, an empty string has no entity.
+func ParseMessageEntities(messageEntityList string) ([]*MessageEntity, error) {
+	if messageEntityList == "" {
+		return nil, nil
+	}
+	var entities []*MessageEntity
+	if err := json.Unmarshal([]byte(messageEntityList), &entities); err != nil {
+		return nil, err
+	}
+	return entities, nil
+}
+
+// MessageEntitiesToString encodes entities as a messageEntityList JSON string.
+func MessageEntitiesToString(entities []*MessageEntity) (string, error) {
+	if entities == nil {
+		entities = []*MessageEntity{}
+	}
+	data, err := json.Marshal(entities)
+	if err != nil {
+		return "", err
+	}
+	return string(data), nil
+}
+
+// InRange reports whether the entity covers a range inside a text of textLen UTF-16 code units,
+// the unit of entity offsets and lengths on every client platform.
+func (e *MessageEntity) InRange(textLen int32) bool {
+	return e.Offset >= 0 && e.Length >= 0 && e.Offset <= textLen-e.Length
+}
@@ -0,0 +1,36 @@
+package sdk_struct
+
+import "testing"
+
+func TestMessageEntitiesString(t *testing.T) {
+	entities := []*MessageEntity{
+		{Type: "bold", Offset: 0, Length: 4},
+		{Type: "url", Offset: 5, Length: 3, Url: "https://example.com", Ex: "ex"},
+	}
+	s, err := MessageEntitiesToString(entities)
+	if err != nil {
+		t.Fatal(err)
+	}
+	parsed, err := ParseMessageEntities(s)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(parsed) != len(entities) {
+		t.Fatalf("parsed %d entities from %s, want %d", len(parsed), s, len(entities))
+	}
+	for i, entity := range entities {
+		if *parsed[i] != *entity {
+			t.Errorf("entity %d = %+v, want %+v", i, parsed[i], entity)
+		}
+	}
+
+	if s, err := MessageEntitiesToString(nil); err != nil || s != "[]" {
+		t.Errorf("no entity = %q, %v, want []", s, err)
+	}
+	if parsed, err := ParseMessageEntities(""); err != nil || parsed != nil {
+		t.Errorf("empty string = %v, %v, want no entity", parsed, err)
+	}
+	if _, err := ParseMessageEntities("{"); err == nil {
+		t.Error("invalid JSON was parsed")
+	}
+}
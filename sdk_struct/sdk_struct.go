@@ -311,7 +311,7 @@ type IMConfig struct {
 	SystemType          string `json:"systemType"`
 	PlatformID          int32  `json:"platformID"`
 	ApiAddr             string `json:"apiAddr"`
-	WsAddr              string `json:"wsAddr"`
+	WsAddr              string `json:"wsAddr"` // comma-separated gateways are tried in turn on connect failure
 	DataDir             string `json:"dataDir"`
 	LogLevel            uint32 `json:"logLevel"`
 	IsLogStandardOutput bool   `json:"isLogStandardOutput"`
@@ -320,6 +320,51 @@ type IMConfig struct {
 	// StopGoroutineOnBackground
 	// Whether to automatically stop goroutines in the background to prevent iOS watchdog issues
 	StopGoroutineOnBackground bool `json:"stopGoroutineOnBackground"`
+	// TLSConfig
+	// Optional TLS settings for the wss:// gateway and https:// api connections
+	TLSConfig *TLSConfig `json:"tlsConfig,omitempty"`
+	// DefaultSendOptions
+	// Message options such as {"offlinePush": false} applied to every sent message that does not set them itself
+	DefaultSendOptions map[string]bool `json:"defaultSendOptions,omitempty"`
+	// MaxMsgContentSize
+	// Maximum size in bytes of a message's content, larger messages fail to send without reaching the gateway, 0 means no limit
+	MaxMsgContentSize int `json:"maxMsgContentSize,omitempty"`
+	// ConvertOversizedTextToFile
+	// Send text messages over MaxMsgContentSize as a .txt file message instead of failing them
+	ConvertOversizedTextToFile bool `json:"convertOversizedTextToFile,omitempty"`
+	// SendMsgRateLimit
+	// Optional limit on how fast messages are sent to the gateway
+	SendMsgRateLimit *SendMsgRateLimit `json:"sendMsgRateLimit,omitempty"`
+	// ApiRetry
+	// Optional retry policy for failed api requests, nil keeps the default of 3 attempts
+	ApiRetry *ApiRetry `json:"apiRetry,omitempty"`
+}
+
+type ApiRetry struct {
+	// Attempts total number of attempts, 1 disables retrying
+	Attempts int `json:"attempts"`
+	// BackoffMs wait in milliseconds before the first retry, doubled for every further retry
+	BackoffMs int64 `json:"backoffMs"`
+	// MaxBackoffMs upper bound in milliseconds of the wait between two attempts, 0 means no bound
+	MaxBackoffMs int64 `json:"maxBackoffMs,omitempty"`
+}
+
+type SendMsgRateLimit struct {
+	// Rate messages sent per second on average, 0 disables the limit
+	Rate float64 `json:"rate"`
+	// Burst messages that may be sent at once before the rate applies
+	Burst int `json:"burst"`
+	// Reject fails sends over the limit instead of delaying them
+	Reject bool `json:"reject,omitempty"`
+}
+
+type TLSConfig struct {
+	// RootCAs PEM encoded certificates trusted in addition to the system pool
+	RootCAs string `json:"rootCAs,omitempty"`
+	// PinnedSHA256 hex encoded SHA-256 fingerprints, one of which must match a certificate in the server chain
+	PinnedSHA256 []string `json:"pinnedSHA256,omitempty"`
+	// InsecureSkipVerify disables certificate chain verification, for development only
+	InsecureSkipVerify bool `json:"insecureSkipVerify,omitempty"`
 }
 
 type CmdNewMsgComeToConversation struct {
@@ -49,6 +49,10 @@ func registerFunc() {
 	js.Global().Set("getLoginStatus", js.FuncOf(wrapperInitLogin.GetLoginStatus))
 	js.Global().Set("setAppBackgroundStatus", js.FuncOf(wrapperInitLogin.SetAppBackgroundStatus))
 	js.Global().Set("networkStatusChanged", js.FuncOf(wrapperInitLogin.NetworkStatusChanged))
+	js.Global().Set("getNetworkLatency", js.FuncOf(wrapperInitLogin.GetNetworkLatency))
+	js.Global().Set("getDiagnostics", js.FuncOf(wrapperInitLogin.GetDiagnostics))
+	js.Global().Set("clearMemoryCaches", js.FuncOf(wrapperInitLogin.ClearMemoryCaches))
+	js.Global().Set("getTokenInfo", js.FuncOf(wrapperInitLogin.GetTokenInfo))
 
 	//register conversation and message function
 	wrapperConMsg := wasm_wrapper.NewWrapperConMsg(globalFuc)
@@ -81,6 +85,20 @@ func registerFunc() {
 	js.Global().Set("getAtAllTag", js.FuncOf(wrapperConMsg.GetAtAllTag))
 	js.Global().Set("markConversationMessageAsRead", js.FuncOf(wrapperConMsg.MarkConversationMessageAsRead))
 	js.Global().Set("markAllConversationMessageAsRead", js.FuncOf(wrapperConMsg.MarkAllConversationMessageAsRead))
+	js.Global().Set("recalculateUnreadCount", js.FuncOf(wrapperConMsg.RecalculateUnreadCount))
+	js.Global().Set("recalculateAllUnreadCount", js.FuncOf(wrapperConMsg.RecalculateAllUnreadCount))
+	js.Global().Set("addFavorite", js.FuncOf(wrapperConMsg.AddFavorite))
+	js.Global().Set("removeFavorite", js.FuncOf(wrapperConMsg.RemoveFavorite))
+	js.Global().Set("getFavoriteList", js.FuncOf(wrapperConMsg.GetFavoriteList))
+	js.Global().Set("starMessage", js.FuncOf(wrapperConMsg.StarMessage))
+	js.Global().Set("getStarredMessages", js.FuncOf(wrapperConMsg.GetStarredMessages))
+	js.Global().Set("createConversationFolder", js.FuncOf(wrapperConMsg.CreateConversationFolder))
+	js.Global().Set("renameConversationFolder", js.FuncOf(wrapperConMsg.RenameConversationFolder))
+	js.Global().Set("deleteConversationFolder", js.FuncOf(wrapperConMsg.DeleteConversationFolder))
+	js.Global().Set("getConversationFolders", js.FuncOf(wrapperConMsg.GetConversationFolders))
+	js.Global().Set("addConversationsToFolder", js.FuncOf(wrapperConMsg.AddConversationsToFolder))
+	js.Global().Set("removeConversationsFromFolder", js.FuncOf(wrapperConMsg.RemoveConversationsFromFolder))
+	js.Global().Set("getConversationListByFolder", js.FuncOf(wrapperConMsg.GetConversationListByFolder))
 	js.Global().Set("markMessagesAsReadByMsgID", js.FuncOf(wrapperConMsg.MarkMessagesAsReadByMsgID))
 	js.Global().Set("sendMessage", js.FuncOf(wrapperConMsg.SendMessage))
 	js.Global().Set("sendMessageNotOss", js.FuncOf(wrapperConMsg.SendMessageNotOss))
@@ -91,8 +109,11 @@ func registerFunc() {
 	js.Global().Set("getAdvancedHistoryMessageList", js.FuncOf(wrapperConMsg.GetAdvancedHistoryMessageList))
 	js.Global().Set("getAdvancedHistoryMessageListReverse", js.FuncOf(wrapperConMsg.GetAdvancedHistoryMessageListReverse))
 	js.Global().Set("getMultipleConversation", js.FuncOf(wrapperConMsg.GetMultipleConversation))
+	js.Global().Set("getConversationRecvMessageOpt", js.FuncOf(wrapperConMsg.GetConversationRecvMessageOpt))
 	js.Global().Set("hideConversation", js.FuncOf(wrapperConMsg.HideConversation))
 	js.Global().Set("setConversationDraft", js.FuncOf(wrapperConMsg.SetConversationDraft))
+	js.Global().Set("setConversationStructuredDraft", js.FuncOf(wrapperConMsg.SetConversationStructuredDraft))
+	js.Global().Set("getConversationStructuredDraft", js.FuncOf(wrapperConMsg.GetConversationStructuredDraft))
 	js.Global().Set("setConversation", js.FuncOf(wrapperConMsg.SetConversation))
 
 	js.Global().Set("getTotalUnreadMsgCount", js.FuncOf(wrapperConMsg.GetTotalUnreadMsgCount))
@@ -155,6 +176,7 @@ func registerFunc() {
 	js.Global().Set("unsubscribeUsersStatus", js.FuncOf(wrapperUser.UnsubscribeUsersStatus))
 	js.Global().Set("getSubscribeUsersStatus", js.FuncOf(wrapperUser.GetSubscribeUsersStatus))
 	js.Global().Set("getUserStatus", js.FuncOf(wrapperUser.GetUserStatus))
+	js.Global().Set("getOnlineDevices", js.FuncOf(wrapperUser.GetOnlineDevices))
 
 	wrapperFriend := wasm_wrapper.NewWrapperFriend(globalFuc)
 	js.Global().Set("getSpecifiedFriendsInfo", js.FuncOf(wrapperFriend.GetSpecifiedFriendsInfo))
@@ -592,6 +592,24 @@ func (i *LocalChatLogs) GetUnreadMessage(ctx context.Context, conversationID str
 	}
 }
 
+// GetBurnedPrivateChatMessages gets the read private chat messages whose burn duration has passed
+func (i *LocalChatLogs) GetBurnedPrivateChatMessages(ctx context.Context, conversationID string, now int64) (result []*model_struct.LocalChatLog, err error) {
+	msgs, err := exec.Exec(conversationID, now)
+	if err != nil {
+		return nil, err
+	} else {
+		if v, ok := msgs.(string); ok {
+			err := utils.JsonStringToStruct(v, &result)
+			if err != nil {
+				return nil, err
+			}
+			return result, err
+		} else {
+			return nil, exec.ErrType
+		}
+	}
+}
+
 func (i *LocalChatLogs) GetMessagesByClientMsgIDs(ctx context.Context, conversationID string, msgIDs []string) (result []*model_struct.LocalChatLog, err error) {
 	msgs, err := exec.Exec(conversationID, utils.StructToJsonString(msgIDs))
 	if err != nil {
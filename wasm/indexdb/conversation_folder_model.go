@@ -0,0 +1,120 @@
+// Copyright © 2023 OpenIM SDK. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//go:build js && wasm
+// +build js,wasm
+
+package indexdb
+
+import (
+	"context"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/utils"
+	"github.com/openimsdk/openim-sdk-core/v3/wasm/exec"
+)
+
+type LocalConversationFolders struct {
+}
+
+func NewLocalConversationFolders() *LocalConversationFolders {
+	return &LocalConversationFolders{}
+}
+
+func (i *LocalConversationFolders) InsertConversationFolder(ctx context.Context, folder *model_struct.LocalConversationFolder) error {
+	_, err := exec.Exec(utils.StructToJsonString(folder))
+	return err
+}
+
+func (i *LocalConversationFolders) UpdateConversationFolder(ctx context.Context, folder *model_struct.LocalConversationFolder) error {
+	_, err := exec.Exec(folder.FolderID, utils.StructToJsonString(folder))
+	return err
+}
+
+func (i *LocalConversationFolders) DeleteConversationFolder(ctx context.Context, folderID string) error {
+	_, err := exec.Exec(folderID)
+	return err
+}
+
+func (i *LocalConversationFolders) GetConversationFolder(ctx context.Context, folderID string) (*model_struct.LocalConversationFolder, error) {
+	f, err := exec.Exec(folderID)
+	if err != nil {
+		return nil, err
+	}
+	v, ok := f.(string)
+	if !ok {
+		return nil, exec.ErrType
+	}
+	result := model_struct.LocalConversationFolder{}
+	if err := utils.JsonStringToStruct(v, &result); err != nil {
+		return nil, err
+	}
+	return &result, nil
+}
+
+func (i *LocalConversationFolders) GetAllConversationFolders(ctx context.Context) (result []*model_struct.LocalConversationFolder, err error) {
+	fList, err := exec.Exec()
+	if err != nil {
+		return nil, err
+	}
+	v, ok := fList.(string)
+	if !ok {
+		return nil, exec.ErrType
+	}
+	var temp []model_struct.LocalConversationFolder
+	if err := utils.JsonStringToStruct(v, &temp); err != nil {
+		return nil, err
+	}
+	for _, v := range temp {
+		v1 := v
+		result = append(result, &v1)
+	}
+	return result, nil
+}
+
+func (i *LocalConversationFolders) BatchInsertFolderConversations(ctx context.Context, folderConversations []*model_struct.LocalFolderConversation) error {
+	if len(folderConversations) == 0 {
+		return nil
+	}
+	_, err := exec.Exec(utils.StructToJsonString(folderConversations))
+	return err
+}
+
+func (i *LocalConversationFolders) DeleteFolderConversations(ctx context.Context, folderID string, conversationIDs []string) error {
+	if len(conversationIDs) == 0 {
+		return nil
+	}
+	_, err := exec.Exec(folderID, utils.StructToJsonString(conversationIDs))
+	return err
+}
+
+func (i *LocalConversationFolders) GetConversationListByFolderDB(ctx context.Context, folderID string, offset, count int) (result []*model_struct.LocalConversation, err error) {
+	cList, err := exec.Exec(folderID, offset, count)
+	if err != nil {
+		return nil, err
+	}
+	v, ok := cList.(string)
+	if !ok {
+		return nil, exec.ErrType
+	}
+	var temp []model_struct.LocalConversation
+	if err := utils.JsonStringToStruct(v, &temp); err != nil {
+		return nil, err
+	}
+	for _, v := range temp {
+		v1 := v
+		result = append(result, &v1)
+	}
+	return result, nil
+}
@@ -294,6 +294,11 @@ func (i *LocalConversations) SetConversationDraftDB(ctx context.Context, convers
 	return err
 }
 
+func (i *LocalConversations) SetConversationStructuredDraftDB(ctx context.Context, conversationID, draftText, structuredDraft string) error {
+	_, err := exec.Exec(conversationID, draftText, structuredDraft)
+	return err
+}
+
 func (i *LocalConversations) RemoveConversationDraft(ctx context.Context, conversationID, draftText string) error {
 	_, err := exec.Exec(conversationID, draftText)
 	return err
@@ -0,0 +1,63 @@
+// Copyright © 2023 OpenIM SDK. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//go:build js && wasm
+// +build js,wasm
+
+package indexdb
+
+import (
+	"context"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/utils"
+	"github.com/openimsdk/openim-sdk-core/v3/wasm/exec"
+)
+
+type LocalFavorites struct {
+}
+
+func NewLocalFavorites() *LocalFavorites {
+	return &LocalFavorites{}
+}
+
+func (i *LocalFavorites) InsertFavorite(ctx context.Context, favorite *model_struct.LocalFavorite) error {
+	_, err := exec.Exec(utils.StructToJsonString(favorite))
+	return err
+}
+
+func (i *LocalFavorites) DeleteFavorite(ctx context.Context, conversationID, clientMsgID string) error {
+	_, err := exec.Exec(conversationID, clientMsgID)
+	return err
+}
+
+func (i *LocalFavorites) GetFavoriteList(ctx context.Context, offset, count int) (result []*model_struct.LocalFavorite, err error) {
+	fList, err := exec.Exec(offset, count)
+	if err != nil {
+		return nil, err
+	}
+	v, ok := fList.(string)
+	if !ok {
+		return nil, exec.ErrType
+	}
+	var temp []model_struct.LocalFavorite
+	if err := utils.JsonStringToStruct(v, &temp); err != nil {
+		return nil, err
+	}
+	for _, v := range temp {
+		v1 := v
+		result = append(result, &v1)
+	}
+	return result, nil
+}
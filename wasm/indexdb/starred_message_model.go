@@ -0,0 +1,76 @@
+// Copyright © 2023 OpenIM SDK. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//go:build js && wasm
+// +build js,wasm
+
+package indexdb
+
+import (
+	"context"
+
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/db/model_struct"
+	"github.com/openimsdk/openim-sdk-core/v3/pkg/utils"
+	"github.com/openimsdk/openim-sdk-core/v3/wasm/exec"
+)
+
+type LocalStarredMessages struct {
+}
+
+func NewLocalStarredMessages() *LocalStarredMessages {
+	return &LocalStarredMessages{}
+}
+
+func (i *LocalStarredMessages) InsertStarredMessage(ctx context.Context, message *model_struct.LocalStarredMessage) error {
+	_, err := exec.Exec(utils.StructToJsonString(message))
+	return err
+}
+
+func (i *LocalStarredMessages) DeleteStarredMessage(ctx context.Context, conversationID, clientMsgID string) error {
+	_, err := exec.Exec(conversationID, clientMsgID)
+	return err
+}
+
+func (i *LocalStarredMessages) DeleteStarredMessages(ctx context.Context, conversationID string, clientMsgIDs []string) error {
+	if len(clientMsgIDs) == 0 {
+		return nil
+	}
+	_, err := exec.Exec(conversationID, utils.StructToJsonString(clientMsgIDs))
+	return err
+}
+
+func (i *LocalStarredMessages) DeleteConversationStarredMessages(ctx context.Context, conversationID string) error {
+	_, err := exec.Exec(conversationID)
+	return err
+}
+
+func (i *LocalStarredMessages) GetStarredMessageList(ctx context.Context, conversationID string) (result []*model_struct.LocalStarredMessage, err error) {
+	sList, err := exec.Exec(conversationID)
+	if err != nil {
+		return nil, err
+	}
+	v, ok := sList.(string)
+	if !ok {
+		return nil, exec.ErrType
+	}
+	var temp []model_struct.LocalStarredMessage
+	if err := utils.JsonStringToStruct(v, &temp); err != nil {
+		return nil, err
+	}
+	for _, v := range temp {
+		v1 := v
+		result = append(result, &v1)
+	}
+	return result, nil
+}
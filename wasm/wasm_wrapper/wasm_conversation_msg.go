@@ -129,6 +129,76 @@ func (w *WrapperConMsg) MarkAllConversationMessageAsRead(_ js.Value, args []js.V
 	return event_listener.NewCaller(open_im_sdk.MarkAllConversationMessageAsRead, callback, &args).AsyncCallWithCallback()
 }
 
+func (w *WrapperConMsg) RecalculateUnreadCount(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.RecalculateUnreadCount, callback, &args).AsyncCallWithCallback()
+}
+
+func (w *WrapperConMsg) RecalculateAllUnreadCount(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.RecalculateAllUnreadCount, callback, &args).AsyncCallWithCallback()
+}
+
+func (w *WrapperConMsg) AddFavorite(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.AddFavorite, callback, &args).AsyncCallWithCallback()
+}
+
+func (w *WrapperConMsg) RemoveFavorite(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.RemoveFavorite, callback, &args).AsyncCallWithCallback()
+}
+
+func (w *WrapperConMsg) GetFavoriteList(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.GetFavoriteList, callback, &args).AsyncCallWithCallback()
+}
+
+func (w *WrapperConMsg) StarMessage(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.StarMessage, callback, &args).AsyncCallWithCallback()
+}
+
+func (w *WrapperConMsg) GetStarredMessages(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.GetStarredMessages, callback, &args).AsyncCallWithCallback()
+}
+
+func (w *WrapperConMsg) CreateConversationFolder(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.CreateConversationFolder, callback, &args).AsyncCallWithCallback()
+}
+
+func (w *WrapperConMsg) RenameConversationFolder(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.RenameConversationFolder, callback, &args).AsyncCallWithCallback()
+}
+
+func (w *WrapperConMsg) DeleteConversationFolder(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.DeleteConversationFolder, callback, &args).AsyncCallWithCallback()
+}
+
+func (w *WrapperConMsg) GetConversationFolders(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.GetConversationFolders, callback, &args).AsyncCallWithCallback()
+}
+
+func (w *WrapperConMsg) AddConversationsToFolder(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.AddConversationsToFolder, callback, &args).AsyncCallWithCallback()
+}
+
+func (w *WrapperConMsg) RemoveConversationsFromFolder(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.RemoveConversationsFromFolder, callback, &args).AsyncCallWithCallback()
+}
+
+func (w *WrapperConMsg) GetConversationListByFolder(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.GetConversationListByFolder, callback, &args).AsyncCallWithCallback()
+}
+
 func (w *WrapperConMsg) MarkMessagesAsReadByMsgID(_ js.Value, args []js.Value) interface{} {
 	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
 	return event_listener.NewCaller(open_im_sdk.MarkMessagesAsReadByMsgID, callback, &args).AsyncCallWithCallback()
@@ -202,6 +272,11 @@ func (w *WrapperConMsg) GetMultipleConversation(_ js.Value, args []js.Value) int
 	return event_listener.NewCaller(open_im_sdk.GetMultipleConversation, callback, &args).AsyncCallWithCallback()
 }
 
+func (w *WrapperConMsg) GetConversationRecvMessageOpt(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.GetConversationRecvMessageOpt, callback, &args).AsyncCallWithCallback()
+}
+
 func (w *WrapperConMsg) FindMessageList(_ js.Value, args []js.Value) interface{} {
 	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
 	return event_listener.NewCaller(open_im_sdk.FindMessageList, callback, &args).AsyncCallWithCallback()
@@ -289,6 +364,16 @@ func (w *WrapperConMsg) SetConversationDraft(_ js.Value, args []js.Value) interf
 	return event_listener.NewCaller(open_im_sdk.SetConversationDraft, callback, &args).AsyncCallWithCallback()
 }
 
+func (w *WrapperConMsg) SetConversationStructuredDraft(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.SetConversationStructuredDraft, callback, &args).AsyncCallWithCallback()
+}
+
+func (w *WrapperConMsg) GetConversationStructuredDraft(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.GetConversationStructuredDraft, callback, &args).AsyncCallWithCallback()
+}
+
 func (w *WrapperConMsg) GetTotalUnreadMsgCount(_ js.Value, args []js.Value) interface{} {
 	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
 	return event_listener.NewCaller(open_im_sdk.GetTotalUnreadMsgCount, callback, &args).AsyncCallWithCallback()
@@ -124,6 +124,23 @@ func (w *WrapperInitLogin) NetworkStatusChanged(_ js.Value, args []js.Value) int
 	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
 	return event_listener.NewCaller(open_im_sdk.NetworkStatusChanged, callback, &args).AsyncCallWithCallback()
 }
+func (w *WrapperInitLogin) GetNetworkLatency(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.GetNetworkLatency, callback, &args).AsyncCallWithCallback()
+}
+func (w *WrapperInitLogin) GetDiagnostics(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.GetDiagnostics, callback, &args).AsyncCallWithCallback()
+}
+func (w *WrapperInitLogin) ClearMemoryCaches(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.ClearMemoryCaches, callback, &args).AsyncCallWithCallback()
+}
+
+func (w *WrapperInitLogin) GetTokenInfo(_ js.Value, args []js.Value) interface{} {
+	callback := event_listener.NewBaseCallback(utils.FirstLower(utils.GetSelfFuncName()), w.commonFunc)
+	return event_listener.NewCaller(open_im_sdk.GetTokenInfo, callback, &args).AsyncCallWithCallback()
+}
 func (w *WrapperInitLogin) GetLoginStatus(_ js.Value, args []js.Value) interface{} {
 	return event_listener.NewCaller(open_im_sdk.GetLoginStatus, nil, &args).AsyncCallWithOutCallback()
 }